@@ -0,0 +1,94 @@
+//! Resetting the FT6336U through a GPIO expander
+//!
+//! This example demonstrates how to drive the touch controller's reset line
+//! through a GPIO expander such as the AW9523B, as on the CoreSE-S3. The
+//! driver's `reset()` accepts any `embedded-hal` `OutputPin`, so an expander
+//! pin works the same as a native GPIO.
+//!
+//! # Hardware Requirements
+//!
+//! - A microcontroller with I2C support
+//! - FT6336U touch controller connected via I2C
+//! - The FT6336U reset line connected to an expander output (P0_0 on the CoreSE-S3)
+//!
+//! # Note
+//!
+//! The expander and bus are simulated here, so the example runs on the host.
+
+// The async driver's reset() needs an executor, so only the blocking build
+// runs the sequence
+#![cfg_attr(feature = "async", allow(dead_code))]
+
+use core::cell::Cell;
+use core::convert::Infallible;
+
+use embedded_hal::digital::{ErrorType, OutputPin};
+
+/// Stand-in for the AW9523B's port 0 output register
+///
+/// A real driver would write the register over I2C whenever a pin changes.
+struct Expander {
+    port0: Cell<u8>,
+}
+
+/// One output pin on the expander, like the pin types expander HALs provide
+struct ExpanderPin<'a> {
+    expander: &'a Expander,
+    bit: u8,
+}
+
+impl ErrorType for ExpanderPin<'_> {
+    type Error = Infallible;
+}
+
+impl OutputPin for ExpanderPin<'_> {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let port = self.expander.port0.get() & !(1 << self.bit);
+        self.expander.port0.set(port);
+        println!("P0 = {port:#010b}");
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let port = self.expander.port0.get() | (1 << self.bit);
+        self.expander.port0.set(port);
+        println!("P0 = {port:#010b}");
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async"))]
+fn main() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::i2c::Mock;
+    use ft6336u_driver::FT6336U;
+
+    let expander = Expander {
+        port0: Cell::new(0xFF),
+    };
+    // TOUCH_RST is P0_0 on the CoreSE-S3
+    let mut touch_rst = ExpanderPin {
+        expander: &expander,
+        bit: 0,
+    };
+
+    // Replace the mock with your I2C peripheral and a real delay
+    let mut i2c = Mock::new(&[]);
+    let mut delay = NoopDelay::new();
+    let mut touch = FT6336U::new(i2c.clone());
+
+    touch.reset(&mut touch_rst, &mut delay).unwrap();
+
+    // An expander pin write is itself an I2C transaction, so a slow bus may
+    // call for a longer boot wait
+    touch
+        .reset_with_timing(&mut touch_rst, &mut delay, 10, 500)
+        .unwrap();
+
+    i2c.done();
+}
+
+#[cfg(feature = "async")]
+fn main() {
+    println!("With the async feature, await reset() from your executor instead.");
+}
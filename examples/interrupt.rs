@@ -1,74 +1,238 @@
 //! Interrupt-driven example for the FT6336U touch controller
 //!
-//! This example demonstrates how to use the touch controller in interrupt mode,
-//! which is more power-efficient than continuous polling.
+//! This example demonstrates the full bring-up sequence for interrupt mode,
+//! which is more power-efficient than continuous polling: reset the
+//! controller, configure it to pulse INT for each report, then wait on the
+//! pin and scan only when it fires.
 //!
 //! # Hardware Requirements
 //!
 //! - A microcontroller with I2C support and GPIO interrupts
 //! - FT6336U touch controller connected via I2C
 //! - Interrupt pin connected from FT6336U to MCU GPIO
-//! - Optional: Reset pin for touch controller initialization
+//! - Reset pin connected from an MCU or expander output
 //!
-//! # Note
+//! # Running It
 //!
-//! This is a no_run example as it requires actual hardware.
+//! The bus and pins are simulated here, so the example runs on the host.
+//! `touch_task` only depends on `embedded-hal` traits and can be copied into
+//! firmware unchanged. With `--features async` it awaits the pin through
+//! `embedded_hal_async::digital::Wait`, which esp-hal's `Input` implements,
+//! so on an ESP32 with embassy the task is driven roughly like this (exact
+//! constructor names vary between esp-hal releases):
+//!
+//! ```text
+//! let peripherals = esp_hal::init(esp_hal::Config::default());
+//! let i2c = I2c::new(peripherals.I2C0, i2c::master::Config::default())
+//!     .unwrap()
+//!     .with_sda(peripherals.GPIO12)
+//!     .with_scl(peripherals.GPIO11)
+//!     .into_async();
+//! let mut rst = Output::new(peripherals.GPIO10, Level::High, OutputConfig::default());
+//! let mut int = Input::new(peripherals.GPIO9, InputConfig::default().with_pull(Pull::Up));
+//!
+//! touch_task(i2c, &mut rst, &mut int, &mut embassy_time::Delay, usize::MAX).await.unwrap();
+//! ```
+//!
+//! Without the `async` feature the blocking driver polls the pin level
+//! between short delays instead.
+
+use ft6336u_driver::{Config, Error, GestureMode, TouchData, TouchStatus};
+
+/// Configuration used once the controller is out of reset
+///
+/// Trigger mode makes the controller pulse INT once per touch report, rather
+/// than holding it low for as long as a finger is down.
+fn touch_config() -> Config {
+    Config::recommended().with_gesture_mode(GestureMode::Trigger)
+}
+
+/// Print what changed in one frame
+fn report(frame: &TouchData) {
+    for (slot, point) in frame.points.iter().enumerate() {
+        match point.status {
+            TouchStatus::Touch => println!("Touch #{slot} started at ({}, {})", point.x, point.y),
+            TouchStatus::Stream => println!("Touch #{slot} at ({}, {})", point.x, point.y),
+            TouchStatus::Release => {}
+        }
+    }
+    if frame.is_empty() {
+        println!("All touches ended");
+    }
+}
+
+/// Reset, configure, then handle `frames` interrupts
+///
+/// Pass `usize::MAX` to keep handling touches forever.
+#[cfg(feature = "async")]
+async fn touch_task<I2C, RST, INT, D>(
+    i2c: I2C,
+    rst: &mut RST,
+    int: &mut INT,
+    delay: &mut D,
+    frames: usize,
+) -> Result<(), Error<I2C::Error>>
+where
+    I2C: embedded_hal_async::i2c::I2c,
+    RST: embedded_hal::digital::OutputPin,
+    INT: embedded_hal_async::digital::Wait,
+    D: embedded_hal_async::delay::DelayNs,
+{
+    use ft6336u_driver::FT6336U;
+
+    let mut touch = FT6336U::new(i2c);
+
+    // Reset, then wait for the controller to answer with its chip ID
+    touch.reset(rst, delay).await?;
+    touch.init_ready(delay, 300).await?;
+
+    touch.configure(&touch_config()).await?;
+
+    // Each frame waits for INT to go low, then scans
+    let mut stream = touch.frames(int);
+    for _ in 0..frames {
+        let Some(frame) = stream.next().await else {
+            break;
+        };
+        report(&frame?);
+    }
+    Ok(())
+}
+
+/// Reset, configure, then handle `frames` interrupts
+///
+/// Pass `usize::MAX` to keep handling touches forever.
+#[cfg(not(feature = "async"))]
+fn touch_task<I2C, RST, INT, D>(
+    i2c: I2C,
+    rst: &mut RST,
+    int: &mut INT,
+    delay: &mut D,
+    frames: usize,
+) -> Result<(), Error<I2C::Error>>
+where
+    I2C: embedded_hal::i2c::I2c,
+    RST: embedded_hal::digital::OutputPin,
+    INT: embedded_hal::digital::InputPin,
+    D: embedded_hal::delay::DelayNs,
+{
+    use ft6336u_driver::FT6336U;
+
+    let mut touch = FT6336U::new(i2c);
+
+    // Reset, then wait for the controller to answer with its chip ID
+    touch.reset(rst, delay)?;
+    touch.init_ready(delay, 300)?;
+
+    touch.configure(&touch_config())?;
 
+    // Check the pin every 5 ms, giving up after a minute without touches
+    for _ in 0..frames {
+        match touch.wait_for_touch(int, delay, 5, 60_000) {
+            Ok(frame) => report(&frame),
+            Err(Error::Timeout) => println!("No touch for a minute"),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// Bus traffic for the whole run: reset check, configuration, two reports
+fn i2c_expectations() -> Vec<embedded_hal_mock::eh1::i2c::Transaction> {
+    use embedded_hal_mock::eh1::i2c::Transaction;
+    use ft6336u_driver::I2C_ADDR;
+
+    vec![
+        // Chip ID, once the controller is out of reset
+        Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64]),
+        // configure()
+        Transaction::write(I2C_ADDR, vec![0xA4, 0x01]),
+        Transaction::write(I2C_ADDR, vec![0x86, 0x01]),
+        Transaction::write(I2C_ADDR, vec![0x80, 22]),
+        Transaction::write(I2C_ADDR, vec![0x88, 60]),
+        Transaction::write(I2C_ADDR, vec![0x89, 25]),
+        Transaction::write(I2C_ADDR, vec![0x87, 5]),
+        // A finger at (300, 200), then lifted
+        Transaction::write_read(
+            I2C_ADDR,
+            vec![0x02],
+            vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+        ),
+        Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00; 7]),
+    ]
+}
+
+#[cfg(feature = "async")]
+fn main() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::i2c::Mock;
+
+    let mut i2c = Mock::new(&i2c_expectations());
+    let mut rst = PinMock::new(&[
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+    ]);
+    let mut int = PinMock::new(&[
+        PinTransaction::wait_for_state(State::Low),
+        PinTransaction::wait_for_state(State::Low),
+    ]);
+    let mut delay = NoopDelay::new();
+
+    block_on(touch_task(i2c.clone(), &mut rst, &mut int, &mut delay, 2)).unwrap();
+
+    i2c.done();
+    rst.done();
+    int.done();
+}
+
+#[cfg(not(feature = "async"))]
 fn main() {
-    // This example demonstrates the structure for embedded use
-    // In a real embedded application, you would:
-    // Initialize your hardware
-    // let peripherals = Peripherals::take();
-
-    // Initialize I2C
-    // let i2c = I2c::new(peripherals.I2C0, sda, scl, 400u32.kHz());
-    // let mut touch = FT6336U::new(i2c);
-
-    // Configure the touch controller for interrupt mode
-    // touch.write_g_mode(GestureMode::Trigger).unwrap();
-
-    // Configure your interrupt pin
-    // let mut touch_int = Input::new(touch_int_pin, Pull::Up);
-    // touch_int.listen(Event::FallingEdge);
-
-    // Read device info for verification
-    // let chip_id = touch.read_chip_id().unwrap();
-    // println!("FT6336U Chip ID: 0x{:02X}", chip_id);
-
-    // let firmware_id = touch.read_firmware_id().unwrap();
-    // println!("Firmware ID: 0x{:02X}", firmware_id);
-
-    // Main loop
-    // loop {
-    //     // Wait for interrupt
-    //     touch_int.wait_for_falling_edge().await;
-    //
-    //     // Read touch data
-    //     match touch.scan() {
-    //         Ok(data) => {
-    //             if data.touch_count > 0 {
-    //                 for i in 0..data.touch_count as usize {
-    //                     let point = &data.points[i];
-    //
-    //                     match point.status {
-    //                         TouchStatus::Touch => {
-    //                             println!("Touch #{} started at ({}, {})", i, point.x, point.y);
-    //                         }
-    //                         TouchStatus::Stream => {
-    //                             println!("Touch #{} at ({}, {})", i, point.x, point.y);
-    //                         }
-    //                         TouchStatus::Release => {
-    //                             println!("Touch #{} ended", i);
-    //                         }
-    //                     }
-    //                 }
-    //             }
-    //         }
-    //         Err(e) => {
-    //             println!("Touch read error: {:?}", e);
-    //         }
-    //     }
-    // }
-
-    println!("This is a template for embedded use. See comments for implementation.");
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::i2c::Mock;
+
+    let mut i2c = Mock::new(&i2c_expectations());
+    let mut rst = PinMock::new(&[
+        PinTransaction::set(State::Low),
+        PinTransaction::set(State::High),
+    ]);
+    // INT stays high for one check before each report
+    let mut int = PinMock::new(&[
+        PinTransaction::get(State::High),
+        PinTransaction::get(State::Low),
+        PinTransaction::get(State::High),
+        PinTransaction::get(State::Low),
+    ]);
+    let mut delay = NoopDelay::new();
+
+    touch_task(i2c.clone(), &mut rst, &mut int, &mut delay, 2).unwrap();
+
+    i2c.done();
+    rst.done();
+    int.done();
+}
+
+/// Run a future to completion on the current thread
+///
+/// Stands in for an executor such as embassy's. The mocks never actually
+/// wait, so polling in a loop is enough here.
+#[cfg(feature = "async")]
+fn block_on<F: core::future::Future>(future: F) -> F::Output {
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = core::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
 }
@@ -0,0 +1,148 @@
+//! Configuration presets for the FT6336U touch controller.
+//!
+//! This module contains the [`Config`] struct, which groups the operating
+//! parameters that are usually set once at startup so they can be applied in
+//! a single call to the driver's `configure()` method, and, with the
+//! `gestures` feature, [`GestureParams`], which groups the gesture tuning
+//! registers.
+
+#[cfg(feature = "gestures")]
+use super::constants::*;
+use super::types::*;
+
+/// Operating parameters applied by the driver's `configure()` method
+///
+/// Start from [`Config::recommended`] and adjust individual values with the
+/// `with_*` builder methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{Config, GestureMode};
+///
+/// let config = Config::recommended()
+///     .with_gesture_mode(GestureMode::Trigger)
+///     .with_threshold(30);
+///
+/// assert_eq!(config.threshold, 30);
+/// assert_eq!(config.active_rate, 60);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Interrupt mode (polling or trigger)
+    pub gesture_mode: GestureMode,
+    /// Whether the controller may drop to monitor mode when idle
+    pub ctrl_mode: CtrlMode,
+    /// Touch detection threshold (lower = more sensitive)
+    pub threshold: u8,
+    /// Active mode report rate in Hz
+    pub active_rate: u8,
+    /// Monitor mode report rate in Hz
+    pub monitor_rate: u8,
+    /// Seconds without touches before entering monitor mode
+    pub time_enter_monitor: u8,
+}
+
+impl Config {
+    /// A reasonable starting configuration for general capacitive panels
+    ///
+    /// Polling mode, threshold 22, 60Hz active rate, 25Hz monitor rate, and a
+    /// switch to monitor mode after 5 seconds without touches. These are
+    /// starting points rather than universal values: panel size, cover glass
+    /// thickness and electrical noise all affect the best threshold and rates.
+    pub fn recommended() -> Self {
+        Self {
+            gesture_mode: GestureMode::Polling,
+            ctrl_mode: CtrlMode::SwitchToMonitor,
+            threshold: 22,
+            active_rate: 60,
+            monitor_rate: 25,
+            time_enter_monitor: 5,
+        }
+    }
+
+    /// Set the interrupt mode
+    pub fn with_gesture_mode(mut self, mode: GestureMode) -> Self {
+        self.gesture_mode = mode;
+        self
+    }
+
+    /// Set the control (power) mode
+    pub fn with_ctrl_mode(mut self, mode: CtrlMode) -> Self {
+        self.ctrl_mode = mode;
+        self
+    }
+
+    /// Set the touch detection threshold
+    pub fn with_threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the active mode report rate in Hz
+    pub fn with_active_rate(mut self, rate: u8) -> Self {
+        self.active_rate = rate;
+        self
+    }
+
+    /// Set the monitor mode report rate in Hz
+    pub fn with_monitor_rate(mut self, rate: u8) -> Self {
+        self.monitor_rate = rate;
+        self
+    }
+
+    /// Set the idle time in seconds before entering monitor mode
+    pub fn with_time_enter_monitor(mut self, seconds: u8) -> Self {
+        self.time_enter_monitor = seconds;
+        self
+    }
+}
+
+/// Gesture tuning registers, read and written as a group
+///
+/// Returned by the driver's `read_gesture_params()` and applied with
+/// `apply_gesture_params()`, so a tuning can be snapshotted and restored in
+/// one call. [`Default`] holds the power-on values.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::GestureParams;
+///
+/// let params = GestureParams {
+///     distance_zoom: 0x40,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(params.radian_value, 0x0A);
+/// ```
+#[cfg(feature = "gestures")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GestureParams {
+    /// Minimum angle for rotation gestures (`ID_G_RADIAN_VALUE`)
+    pub radian_value: u8,
+    /// Maximum offset for left/right swipes (`ID_G_OFFSET_LEFT_RIGHT`)
+    pub offset_left_right: u8,
+    /// Maximum offset for up/down swipes (`ID_G_OFFSET_UP_DOWN`)
+    pub offset_up_down: u8,
+    /// Minimum distance for left/right swipes (`ID_G_DISTANCE_LEFT_RIGHT`)
+    pub distance_left_right: u8,
+    /// Minimum distance for up/down swipes (`ID_G_DISTANCE_UP_DOWN`)
+    pub distance_up_down: u8,
+    /// Minimum distance for zoom gestures (`ID_G_DISTANCE_ZOOM`)
+    pub distance_zoom: u8,
+}
+
+#[cfg(feature = "gestures")]
+impl Default for GestureParams {
+    fn default() -> Self {
+        Self {
+            radian_value: DEFAULT_RADIAN_VALUE,
+            offset_left_right: DEFAULT_GESTURE_OFFSET,
+            offset_up_down: DEFAULT_GESTURE_OFFSET,
+            distance_left_right: DEFAULT_GESTURE_DISTANCE,
+            distance_up_down: DEFAULT_GESTURE_DISTANCE,
+            distance_zoom: DEFAULT_DISTANCE_ZOOM,
+        }
+    }
+}
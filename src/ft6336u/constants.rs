@@ -10,14 +10,95 @@
 /// FT6336U I2C address
 pub const I2C_ADDR: u8 = 0x38;
 
+/// Chip ID reported by the FT6336U in the chip ID register
+pub const CHIP_ID: u8 = 0x64;
+
 // =============================================================================
 // Touch Parameters
 // =============================================================================
 
-/// Touch press down flag
-pub const PRES_DOWN: u8 = 0x02;
-/// Coordinate up/down flag
-pub const COORD_UD: u8 = 0x01;
+/// Maximum number of simultaneous touch points the FT6336U reports
+pub const MAX_TOUCH_POINTS: usize = 2;
+
+// Per-point event flags (bits 7:6 of `Pn_XH`)
+/// Event flag: the point was just pressed
+pub const EVENT_PRESS_DOWN: u8 = 0b00;
+/// Event flag: the point was just lifted
+pub const EVENT_LIFT_UP: u8 = 0b01;
+/// Event flag: the point is in continued contact
+pub const EVENT_CONTACT: u8 = 0b10;
+/// Event flag: no event for this point
+pub const EVENT_NO_EVENT: u8 = 0b11;
+
+// Names used for the same event flags by FocalTech's reference driver
+/// Event flag: the finger is held down (same value as [`EVENT_CONTACT`])
+pub const PRES_DOWN: u8 = EVENT_CONTACT;
+/// Event flag: the finger was lifted (same value as [`EVENT_LIFT_UP`])
+pub const COORD_UD: u8 = EVENT_LIFT_UP;
+
+// =============================================================================
+// Timing
+// =============================================================================
+
+/// Time to hold the reset line low, in milliseconds
+pub const RESET_LOW_MS: u32 = 10;
+/// Time from releasing reset until the controller is ready, in milliseconds
+pub const RESET_READY_MS: u32 = 300;
+/// Interval between chip ID reads while waiting for the controller to boot, in milliseconds
+pub const READY_POLL_MS: u32 = 10;
+/// Time for the controller to switch device modes, in milliseconds
+pub const MODE_SWITCH_MS: u32 = 100;
+/// Time to wait after starting or storing a calibration, in milliseconds
+pub const CALIBRATION_SETTLE_MS: u32 = 300;
+/// Interval between device mode reads while a calibration runs, in milliseconds
+pub const CALIBRATION_POLL_MS: u32 = 20;
+/// Maximum time a calibration may take, in milliseconds
+pub const CALIBRATION_TIMEOUT_MS: u32 = 2000;
+/// Interval between device mode reads while a factory scan runs, in milliseconds
+pub const FACTORY_SCAN_POLL_MS: u32 = 1;
+/// Maximum time a factory scan may take, in milliseconds
+pub const FACTORY_SCAN_TIMEOUT_MS: u32 = 500;
+
+// =============================================================================
+// Report Rates
+// =============================================================================
+//
+// The rate registers accept any byte, but 0 stops touch reporting and the
+// controller can't scan faster than about 100Hz, so the checked writers only
+// accept this range.
+
+/// Lowest report rate accepted by the checked rate writers, in Hz
+pub const MIN_REPORT_RATE_HZ: u8 = 1;
+/// Highest report rate accepted by the checked rate writers, in Hz
+pub const MAX_REPORT_RATE_HZ: u8 = 100;
+
+// =============================================================================
+// Power-on Defaults
+// =============================================================================
+//
+// Register values after a hardware reset, taken from FocalTech's FT6x36
+// register description. Panel vendors sometimes ship firmware tuned for a
+// particular panel, so read the registers after a reset if exact values
+// matter for your module.
+
+/// Default touch detection threshold (`ID_G_THGROUP`)
+pub const DEFAULT_THRESHOLD: u8 = 0x16;
+/// Default filter coefficient (`ID_G_THDIFF`)
+pub const DEFAULT_FILTER_COE: u8 = 0xA0;
+/// Default seconds without touches before entering monitor mode
+pub const DEFAULT_TIME_ENTER_MONITOR: u8 = 0x0A;
+/// Default active mode report rate
+pub const DEFAULT_ACTIVE_RATE: u8 = 0x0E;
+/// Default monitor mode report rate
+pub const DEFAULT_MONITOR_RATE: u8 = 0x28;
+/// Default gesture radian value
+pub const DEFAULT_RADIAN_VALUE: u8 = 0x0A;
+/// Default gesture offset, used for both left/right and up/down
+pub const DEFAULT_GESTURE_OFFSET: u8 = 0x19;
+/// Default swipe gesture distance, used for both left/right and up/down
+pub const DEFAULT_GESTURE_DISTANCE: u8 = 0x19;
+/// Default zoom gesture distance
+pub const DEFAULT_DISTANCE_ZOOM: u8 = 0x32;
 
 // =============================================================================
 // Register Addresses
@@ -34,13 +115,19 @@ pub const ADDR_GESTURE_ID: u8 = 0x01;
 pub const ADDR_TD_STATUS: u8 = 0x02;
 
 // Touch Point 1 Registers
-/// Touch point 1 event register address
+//
+// Each touch point occupies six consecutive registers:
+//   Pn_XH (event flag in bits 7:6, X[11:8] in bits 3:0), Pn_XL (X[7:0]),
+//   Pn_YH (touch ID in bits 7:4, Y[11:8] in bits 3:0), Pn_YL (Y[7:0]),
+//   Pn_WEIGHT, Pn_MISC (touch area in bits 7:4)
+// The event and ID values therefore share registers with the coordinates.
+/// Touch point 1 event register address (bits 7:6 of `P1_XH`)
 pub const ADDR_TOUCH1_EVENT: u8 = 0x03;
-/// Touch point 1 ID register address
+/// Touch point 1 ID register address (bits 7:4 of `P1_YH`)
 pub const ADDR_TOUCH1_ID: u8 = 0x05;
-/// Touch point 1 X coordinate register address
+/// Touch point 1 X coordinate register address (`P1_XH`, followed by `P1_XL`)
 pub const ADDR_TOUCH1_X: u8 = 0x03;
-/// Touch point 1 Y coordinate register address
+/// Touch point 1 Y coordinate register address (`P1_YH`, followed by `P1_YL`)
 pub const ADDR_TOUCH1_Y: u8 = 0x05;
 /// Touch point 1 weight register address
 pub const ADDR_TOUCH1_WEIGHT: u8 = 0x07;
@@ -48,19 +135,40 @@ pub const ADDR_TOUCH1_WEIGHT: u8 = 0x07;
 pub const ADDR_TOUCH1_MISC: u8 = 0x08;
 
 // Touch Point 2 Registers
-/// Touch point 2 event register address
+/// Touch point 2 event register address (bits 7:6 of `P2_XH`)
 pub const ADDR_TOUCH2_EVENT: u8 = 0x09;
-/// Touch point 2 ID register address
+/// Touch point 2 ID register address (bits 7:4 of `P2_YH`)
 pub const ADDR_TOUCH2_ID: u8 = 0x0B;
-/// Touch point 2 X coordinate register address
+/// Touch point 2 X coordinate register address (`P2_XH`, followed by `P2_XL`)
 pub const ADDR_TOUCH2_X: u8 = 0x09;
-/// Touch point 2 Y coordinate register address
+/// Touch point 2 Y coordinate register address (`P2_YH`, followed by `P2_YL`)
 pub const ADDR_TOUCH2_Y: u8 = 0x0B;
 /// Touch point 2 weight register address
 pub const ADDR_TOUCH2_WEIGHT: u8 = 0x0D;
 /// Touch point 2 miscellaneous data register address
 pub const ADDR_TOUCH2_MISC: u8 = 0x0E;
 
+// Factory Mode Registers
+//
+// Only valid while the device is in factory mode. These follow FocalTech's
+// FT6x36 factory test sequence and are not part of the public datasheet.
+/// Start-scan bit in the device mode register (factory mode only, self-clearing)
+pub const FACTORY_START_SCAN: u8 = 0x80;
+/// Raw channel data buffer register address (factory mode only)
+pub const ADDR_FACTORY_RAW_DATA: u8 = 0x6A;
+/// Data type select register address (factory mode only)
+pub const ADDR_FACTORY_DATA_SELECT: u8 = 0x06;
+/// Data type select value for raw channel data
+pub const FACTORY_DATA_RAW: u8 = 0x00;
+/// Data type select value for baseline-subtracted difference data
+pub const FACTORY_DATA_DIFF: u8 = 0x01;
+/// Factory command register address (shares `TD_STATUS`'s address, factory mode only)
+pub const ADDR_FACTORY_CMD: u8 = 0x02;
+/// Factory command that starts a baseline calibration
+pub const FACTORY_CMD_CALIBRATE: u8 = 0x04;
+/// Factory command that stores the calibration result
+pub const FACTORY_CMD_STORE_CALIBRATION: u8 = 0x05;
+
 // Mode Parameter Registers
 /// Touch detection threshold register address
 pub const ADDR_THRESHOLD: u8 = 0x80;
@@ -96,10 +204,12 @@ pub const ADDR_LIBRARY_VERSION_H: u8 = 0xA1;
 pub const ADDR_LIBRARY_VERSION_L: u8 = 0xA2;
 /// Chip ID register address
 pub const ADDR_CHIP_ID: u8 = 0xA3;
-/// Gesture mode register address
+/// Gesture mode register address (bit 0 selects polling or trigger interrupts)
 pub const ADDR_G_MODE: u8 = 0xA4;
 /// Power mode register address
 pub const ADDR_POWER_MODE: u8 = 0xA5;
+/// Power mode value that hibernates the controller until its next reset
+pub const POWER_MODE_HIBERNATE: u8 = 0x03;
 /// Firmware ID register address
 pub const ADDR_FIRMWARE_ID: u8 = 0xA6;
 /// Focaltech ID register address
@@ -108,3 +218,14 @@ pub const ADDR_FOCALTECH_ID: u8 = 0xA8;
 pub const ADDR_RELEASE_CODE_ID: u8 = 0xAF;
 /// Device state register address
 pub const ADDR_STATE: u8 = 0xBC;
+
+// Extended Mode Registers
+//
+// These come from FocalTech's extended touch modes and are not part of the
+// FT6336U datasheet. Support depends on the firmware build, and some builds
+// place them elsewhere.
+/// Default glove (high sensitivity) mode enable register address
+pub const ADDR_GLOVE_MODE: u8 = 0xC0;
+/// Panel resolution register address (`MAX_X_H`, followed by `MAX_X_L`,
+/// `MAX_Y_H` and `MAX_Y_L`)
+pub const ADDR_PANEL_RESOLUTION: u8 = 0x98;
@@ -0,0 +1,91 @@
+//! Driver-agnostic touch controller abstraction.
+//!
+//! [`TouchController`] is the stable interface for code that should work with
+//! any touch controller driver. Frames are exchanged as [`TouchData`] and
+//! [`TouchPoint`](super::types::TouchPoint), so UI and HAL crates can depend on
+//! the trait and those types without naming a concrete driver.
+//!
+//! With the `async` feature enabled, the I/O methods return futures instead of
+//! blocking, matching the driver that is compiled in.
+
+use super::types::TouchData;
+
+/// A touch controller that can be polled for touch frames
+///
+/// Implemented by [`FT6336U`](crate::FT6336U). Methods here are kept to what
+/// most capacitive controllers share; anything chip-specific stays on the
+/// driver itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(not(feature = "async"))]
+/// # {
+/// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+/// use ft6336u_driver::{TouchController, FT6336U, I2C_ADDR};
+///
+/// // Works with any controller, not just the FT6336U
+/// fn touching<T: TouchController>(touch: &mut T) -> Result<bool, T::Error> {
+///     Ok(touch.scan()?.touch_count > 0)
+/// }
+///
+/// let expectations = [Transaction::write_read(
+///     I2C_ADDR,
+///     vec![0x02],
+///     vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+/// )];
+/// let mut i2c = Mock::new(&expectations);
+/// let mut touch = FT6336U::new(i2c.clone());
+///
+/// assert_eq!(touch.max_points(), 2);
+/// assert!(touching(&mut touch).unwrap());
+///
+/// i2c.done();
+/// # }
+/// ```
+#[cfg(not(feature = "async"))]
+pub trait TouchController {
+    /// Error returned by bus operations
+    type Error;
+
+    /// Read the current touch frame
+    fn scan(&mut self) -> Result<TouchData, Self::Error>;
+
+    /// Maximum number of simultaneous touch points the controller reports
+    fn max_points(&self) -> usize;
+
+    /// Set the touch detection threshold (lower = more sensitive)
+    fn set_threshold(&mut self, threshold: u8) -> Result<(), Self::Error>;
+
+    /// Set the report rate while touches are active, in Hz
+    fn set_report_rate(&mut self, rate: u8) -> Result<(), Self::Error>;
+}
+
+/// A touch controller that can be polled for touch frames
+///
+/// Implemented by [`FT6336U`](crate::FT6336U). Methods here are kept to what
+/// most capacitive controllers share; anything chip-specific stays on the
+/// driver itself.
+#[cfg(feature = "async")]
+pub trait TouchController {
+    /// Error returned by bus operations
+    type Error;
+
+    /// Read the current touch frame
+    fn scan(&mut self) -> impl core::future::Future<Output = Result<TouchData, Self::Error>>;
+
+    /// Maximum number of simultaneous touch points the controller reports
+    fn max_points(&self) -> usize;
+
+    /// Set the touch detection threshold (lower = more sensitive)
+    fn set_threshold(
+        &mut self,
+        threshold: u8,
+    ) -> impl core::future::Future<Output = Result<(), Self::Error>>;
+
+    /// Set the report rate while touches are active, in Hz
+    fn set_report_rate(
+        &mut self,
+        rate: u8,
+    ) -> impl core::future::Future<Output = Result<(), Self::Error>>;
+}
@@ -0,0 +1,134 @@
+//! Readable register dumps for diagnostics.
+//!
+//! [`RegisterDump`] wraps a block of register values read with the driver's
+//! `dump_registers()` method and formats them as hex `addr=val` pairs, with
+//! the datasheet name of each register the driver knows about.
+
+use core::fmt;
+
+/// Datasheet name of a register, if the driver knows it
+fn register_name(addr: u8) -> Option<&'static str> {
+    Some(match addr {
+        0x00 => "DEVICE_MODE",
+        0x01 => "GEST_ID",
+        0x02 => "TD_STATUS",
+        0x03 => "P1_XH",
+        0x04 => "P1_XL",
+        0x05 => "P1_YH",
+        0x06 => "P1_YL",
+        0x07 => "P1_WEIGHT",
+        0x08 => "P1_MISC",
+        0x09 => "P2_XH",
+        0x0A => "P2_XL",
+        0x0B => "P2_YH",
+        0x0C => "P2_YL",
+        0x0D => "P2_WEIGHT",
+        0x0E => "P2_MISC",
+        0x80 => "ID_G_THGROUP",
+        0x85 => "ID_G_THDIFF",
+        0x86 => "ID_G_CTRL",
+        0x87 => "ID_G_TIMEENTERMONITOR",
+        0x88 => "ID_G_PERIODACTIVE",
+        0x89 => "ID_G_PERIODMONITOR",
+        0x91 => "ID_G_RADIAN_VALUE",
+        0x92 => "ID_G_OFFSET_LEFT_RIGHT",
+        0x93 => "ID_G_OFFSET_UP_DOWN",
+        0x94 => "ID_G_DISTANCE_LEFT_RIGHT",
+        0x95 => "ID_G_DISTANCE_UP_DOWN",
+        0x96 => "ID_G_DISTANCE_ZOOM",
+        0xA1 => "ID_G_LIB_VERSION_H",
+        0xA2 => "ID_G_LIB_VERSION_L",
+        0xA3 => "ID_G_CIPHER",
+        0xA4 => "ID_G_MODE",
+        0xA5 => "ID_G_PMODE",
+        0xA6 => "ID_G_FIRMID",
+        0xA8 => "ID_G_FOCALTECH_ID",
+        0xAF => "ID_G_RELEASE_CODE_ID",
+        0xBC => "ID_G_STATE",
+        _ => return None,
+    })
+}
+
+/// Consecutive register values starting at a known address
+///
+/// Its `Debug` output lists each register as `NAME(0xAA)=0xVV`, or
+/// `0xAA=0xVV` for addresses without a documented name. The alternate form
+/// (`{:#?}`) puts each register on its own line.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::RegisterDump;
+///
+/// let dump = RegisterDump::new(0x80, &[0x16, 0x3C]);
+/// assert_eq!(dump.get(0x81), Some(0x3C));
+/// assert_eq!(
+///     format!("{dump:?}"),
+///     "[ID_G_THGROUP(0x80)=0x16, 0x81=0x3C]"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RegisterDump<'a> {
+    /// Address of the first register in `values`
+    start: u8,
+    /// Register values, one per consecutive address
+    values: &'a [u8],
+}
+
+impl<'a> RegisterDump<'a> {
+    /// Wrap register values read starting at `start`
+    ///
+    /// # Arguments
+    /// * `start` - Address of the first register
+    /// * `values` - One value per consecutive register
+    pub fn new(start: u8, values: &'a [u8]) -> Self {
+        Self { start, values }
+    }
+
+    /// Address of the first register
+    pub fn start(&self) -> u8 {
+        self.start
+    }
+
+    /// The raw register values
+    pub fn values(&self) -> &'a [u8] {
+        self.values
+    }
+
+    /// Value of the register at `addr`, if the dump covers it
+    pub fn get(&self, addr: u8) -> Option<u8> {
+        let offset = addr.checked_sub(self.start)?;
+        self.values.get(offset as usize).copied()
+    }
+}
+
+/// One register of a dump, formatted as `addr=val`
+struct Entry {
+    /// Register address
+    addr: u8,
+    /// Register value
+    value: u8,
+}
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match register_name(self.addr) {
+            Some(name) => write!(f, "{}(0x{:02X})=0x{:02X}", name, self.addr, self.value),
+            None => write!(f, "0x{:02X}=0x{:02X}", self.addr, self.value),
+        }
+    }
+}
+
+impl fmt::Debug for RegisterDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(offset, &value)| Entry {
+                addr: self.start.wrapping_add(offset as u8),
+                value,
+            });
+        f.debug_list().entries(entries).finish()
+    }
+}
@@ -2,11 +2,30 @@
 //!
 //! This module contains the main driver struct and all its methods
 //! for interacting with the FT6336U hardware.
+//!
+//! The driver has two layers. `read_register`, `write_register` and
+//! `read_block` form the raw layer, which talks to registers by address.
+//! Every other method belongs to the high-level layer built on top of it.
 
-use embedded_hal::i2c::I2c;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{InputPin, OutputPin};
+use embedded_hal::i2c::{Error as _, ErrorKind, I2c};
 
+use super::config::Config;
+#[cfg(feature = "gestures")]
+use super::config::GestureParams;
 use super::constants::*;
+use super::controller::TouchController;
+use super::diagnostics::RegisterDump;
 use super::error::Error;
+use super::event::Event;
+use super::hibernate::HibernateOnDrop;
+use super::history::History;
+use super::input::TouchInput;
+use super::parse::*;
+use super::poll::PollScheduler;
+use super::state::{Scaling, ScanState};
+use super::trace::{TraceEvent, TraceFn};
 use super::types::*;
 
 /// FT6336U capacitive touch controller driver with async I2C interface
@@ -71,48 +90,1195 @@ use super::types::*;
 /// // Read device information (these will return errors with MockI2c)
 /// // let chip_id = touch.read_chip_id().unwrap();
 /// // let firmware_id = touch.read_firmware_id().unwrap();
+/// // Or read the identification registers in one transaction:
+/// // let info = touch.device_info().unwrap();
 /// ```
-pub struct FT6336U<I2C> {
+pub struct FT6336U<I2C, const HISTORY: usize = 0> {
     /// I2C bus for communicating with the touch controller
     i2c: I2C,
-    /// Cached touch point data from last scan
-    touch_data: TouchData,
+    /// Cached touch data and scan processing settings
+    state: ScanState,
+    /// Last gesture returned by `take_gesture`
+    #[cfg(feature = "gestures")]
+    last_gesture: Gesture,
+    /// Whether multi-byte register reads rely on address auto-increment
+    auto_increment: bool,
+    /// Order of the two registers in each touch coordinate
+    byte_order: ByteOrder,
+    /// Number of touch points scans read and report (1 or 2)
+    max_points: u8,
+    /// Gesture mode from the last successful `write_g_mode`
+    gesture_mode: GestureMode,
+    /// Control mode from the last successful `write_ctrl_mode`
+    ctrl_mode: CtrlMode,
+    /// Hook called after each register transfer, if set
+    trace: Option<TraceFn>,
+    /// Register address used for glove mode
+    glove_mode_addr: u8,
+    /// Cadence used by `poll`
+    scheduler: PollScheduler,
+    /// Frames returned by recent scans, oldest first
+    history: History<HISTORY>,
+    /// Whether the last scan followed an interrupt
+    last_source: ScanSource,
+    /// Sequence number given to the last scanned frame
+    seq: u32,
 }
 
-impl<I2C> FT6336U<I2C>
-where
-    I2C: I2c,
-{
-    /// Create a new FT6336U driver instance
+impl<I2C> FT6336U<I2C>
+where
+    I2C: I2c,
+{
+    /// Create a new FT6336U driver instance
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance that implements embedded_hal::i2c::I2c
+    ///
+    /// # Note
+    /// The reset and interrupt pins should be managed by the AW9523B GPIO expander
+    /// or by the calling code. [`Self::reset`] runs the reset sequence on any
+    /// `OutputPin`, including an expander pin.
+    pub fn new(i2c: I2C) -> Self {
+        Self::with_history(i2c)
+    }
+
+    /// Create a driver whose cached touch state starts from `initial`
+    ///
+    /// For resuming after the application restarts while the controller keeps
+    /// running: seeding the cache with the last known frame means a finger
+    /// that is still down is reported as a continuing `Stream` by the first
+    /// scan, not as a new `Touch`. Frame numbering continues from
+    /// `initial.seq`. Press timing for `scan_at` restarts, so `pressed_ms`
+    /// counts from the first timed scan.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    /// * `initial` - The touch data to prime the cache with, usually the last
+    ///   frame scanned before the restart
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TouchData, TouchStatus, I2C_ADDR};
+    ///
+    /// // Saved before the restart: one finger down at (300, 200)
+    /// let mut saved = TouchData::default();
+    /// saved.touch_count = 1;
+    /// saved.points[0].status = TouchStatus::Stream;
+    /// saved.points[0].x = 300;
+    /// saved.points[0].y = 200;
+    /// saved.seq = 41;
+    ///
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x02],
+    ///     vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new_with_data(i2c.clone(), saved);
+    ///
+    /// // The held finger is not reported as a new press
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.points[0].status, TouchStatus::Stream);
+    /// assert_eq!(data.seq, 42);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn new_with_data(i2c: I2C, initial: TouchData) -> Self {
+        let mut touch = Self::new(i2c);
+        touch.state.touch_data = initial;
+        touch.seq = initial.seq;
+        touch
+    }
+
+    /// Create a driver and verify that an FT6336U is on the bus
+    ///
+    /// The fallible counterpart to [`Self::new`]: the chip ID register is read
+    /// immediately, so wiring and address problems show up at construction
+    /// rather than at the first scan. The controller must already be out of
+    /// reset; see [`Self::init_ready`] for waiting on a booting device.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    ///
+    /// # Returns
+    /// `Error::NotPresent` if nothing acknowledges the address,
+    /// `Error::WrongChipId` if the chip ID is not [`CHIP_ID`], or
+    /// `Error::I2c` for any other bus error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{Error, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x11]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    ///
+    /// let touch = FT6336U::open(i2c.clone()).unwrap();
+    ///
+    /// // Some other device answers at the same address
+    /// let result = FT6336U::open(i2c.clone());
+    /// assert!(matches!(result, Err(Error::WrongChipId(0x11))));
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn open(i2c: I2C) -> Result<Self, Error<I2C::Error>> {
+        let mut touch = Self::new(i2c);
+        match touch.read_register(ADDR_CHIP_ID) {
+            Ok(CHIP_ID) => Ok(touch),
+            Ok(id) => Err(Error::WrongChipId(id)),
+            Err(Error::I2c(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {
+                Err(Error::NotPresent)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a driver, verify the chip, and apply a configuration
+    ///
+    /// The one-call bring-up path: [`Self::open`] followed by
+    /// [`Self::configure`]. The controller must already be out of reset. On
+    /// any error the driver is dropped and the error returned, so a
+    /// half-configured driver is never handed out, although registers written
+    /// before a failed write keep their new values on the device.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    /// * `config` - The configuration to apply, e.g. [`Config::recommended`]
+    ///
+    /// # Returns
+    /// The errors of [`Self::open`], or `Error::I2c` if a configuration
+    /// write fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{Config, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64]),
+    ///     Transaction::write(I2C_ADDR, vec![0xA4, 0x00]),
+    ///     Transaction::write(I2C_ADDR, vec![0x86, 0x01]),
+    ///     Transaction::write(I2C_ADDR, vec![0x80, 22]),
+    ///     Transaction::write(I2C_ADDR, vec![0x88, 60]),
+    ///     Transaction::write(I2C_ADDR, vec![0x89, 25]),
+    ///     Transaction::write(I2C_ADDR, vec![0x87, 5]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    ///
+    /// let touch = FT6336U::with_config(i2c.clone(), &Config::recommended()).unwrap();
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn with_config(i2c: I2C, config: &Config) -> Result<Self, Error<I2C::Error>> {
+        let mut touch = Self::open(i2c)?;
+        touch.configure(config)?;
+        Ok(touch)
+    }
+}
+
+impl<I2C, const HISTORY: usize> FT6336U<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    /// Create a driver that keeps the last `HISTORY` scanned frames
+    ///
+    /// Identical to [`FT6336U::new`], except every scan also appends the
+    /// returned frame to a fixed-capacity history, readable through
+    /// [`Self::history`]. The buffer lives inside the driver, so no
+    /// allocation is involved.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    pub fn with_history(i2c: I2C) -> Self {
+        Self {
+            i2c,
+            state: ScanState::default(),
+            #[cfg(feature = "gestures")]
+            last_gesture: Gesture::None,
+            auto_increment: true,
+            byte_order: ByteOrder::BigEndian,
+            max_points: MAX_TOUCH_POINTS as u8,
+            gesture_mode: GestureMode::Trigger,
+            ctrl_mode: CtrlMode::SwitchToMonitor,
+            trace: None,
+            glove_mode_addr: ADDR_GLOVE_MODE,
+            scheduler: PollScheduler::default(),
+            history: History::new(),
+            last_source: ScanSource::Poll,
+            seq: 0,
+        }
+    }
+
+    /// Reset the controller using its reset pin
+    ///
+    /// Drives `rst` low for [`RESET_LOW_MS`], releases it, then waits
+    /// [`RESET_READY_MS`] for the controller to boot before returning. The
+    /// driver's cached touch state and modes are reset to their power-on
+    /// defaults, since the controller has forgotten any previous configuration.
+    ///
+    /// `rst` can be any [`OutputPin`], including a pin on a GPIO expander such
+    /// as the AW9523B, as long as its HAL implements the trait.
+    ///
+    /// # Arguments
+    /// * `rst` - The controller's active-low reset pin
+    /// * `delay` - Delay provider
+    ///
+    /// # Returns
+    /// `Error::Pin` if driving the reset pin fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::delay::NoopDelay;
+    /// use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    /// use embedded_hal_mock::eh1::i2c::Mock;
+    /// use ft6336u_driver::FT6336U;
+    ///
+    /// let mut i2c = Mock::new(&[]);
+    /// let mut rst = PinMock::new(&[
+    ///     PinTransaction::set(State::Low),
+    ///     PinTransaction::set(State::High),
+    /// ]);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.reset(&mut rst, &mut NoopDelay::new()).unwrap();
+    ///
+    /// rst.done();
+    /// i2c.done();
+    /// ```
+    pub fn reset<P, D>(&mut self, rst: &mut P, delay: &mut D) -> Result<(), Error<I2C::Error>>
+    where
+        P: OutputPin,
+        D: DelayNs,
+    {
+        self.reset_with_timing(rst, delay, RESET_LOW_MS, RESET_READY_MS)
+    }
+
+    /// Reset the controller with custom reset timing
+    ///
+    /// Like [`Self::reset`], with the low pulse and boot wait given
+    /// explicitly. Useful when the reset line has extra capacitance, or is
+    /// driven through a GPIO expander such as the AW9523B whose pin writes are
+    /// themselves slow I2C transactions.
+    ///
+    /// # Arguments
+    /// * `rst` - The controller's active-low reset pin
+    /// * `delay` - Delay provider
+    /// * `low_ms` - How long to hold reset low, in milliseconds
+    /// * `ready_ms` - How long to wait after releasing reset, in milliseconds
+    ///
+    /// # Returns
+    /// `Error::Pin` if driving the reset pin fails
+    pub fn reset_with_timing<P, D>(
+        &mut self,
+        rst: &mut P,
+        delay: &mut D,
+        low_ms: u32,
+        ready_ms: u32,
+    ) -> Result<(), Error<I2C::Error>>
+    where
+        P: OutputPin,
+        D: DelayNs,
+    {
+        rst.set_low().map_err(|_| Error::Pin)?;
+        delay.delay_ms(low_ms);
+        rst.set_high().map_err(|_| Error::Pin)?;
+        delay.delay_ms(ready_ms);
+
+        self.state.touch_data = TouchData::default();
+        self.history.clear();
+        #[cfg(feature = "gestures")]
+        {
+            self.last_gesture = Gesture::None;
+        }
+        self.gesture_mode = GestureMode::Trigger;
+        self.ctrl_mode = CtrlMode::SwitchToMonitor;
+        Ok(())
+    }
+
+    /// Consume the driver and return the I2C bus
+    ///
+    /// Use this to repurpose the bus, for example before entering deep sleep.
+    /// The controller itself is left in whatever state it was in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::Mock;
+    /// use ft6336u_driver::FT6336U;
+    ///
+    /// let touch = FT6336U::new(Mock::new(&[]));
+    ///
+    /// let mut i2c = touch.release();
+    /// i2c.done();
+    /// ```
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Wrap the driver so the controller hibernates when it is dropped
+    ///
+    /// Off by default: a plain driver leaves the controller running when
+    /// dropped. See [`HibernateOnDrop`] for the limits of writing from `Drop`.
+    pub fn hibernate_on_drop(self) -> HibernateOnDrop<I2C, HISTORY> {
+        HibernateOnDrop::new(self)
+    }
+
+    /// Borrow the I2C bus for transactions the driver doesn't provide
+    ///
+    /// Transfers made this way bypass the driver entirely: they are not seen
+    /// by the trace hook, and writes that change the controller's mode or
+    /// settings are not reflected in cached state such as
+    /// [`Self::cached_gesture_mode`] or the last scanned touch data.
+    pub fn inner_mut(&mut self) -> &mut I2C {
+        &mut self.i2c
+    }
+
+    /// Ignore touches lighter than a minimum weight
+    ///
+    /// Points whose `WEIGHT` register is below `min_weight` are treated as not
+    /// touching by every scan method. This is a cheap way to reject light
+    /// phantom touches, for example near the panel edges. The default of 0
+    /// disables filtering.
+    ///
+    /// # Arguments
+    /// * `min_weight` - Minimum weight for a point to count as touching
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TouchStatus, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // One touch at (300, 200) with weight 0x05
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x05, 0x00],
+    ///     ),
+    ///     // Same touch pressed harder, weight 0x40
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x40, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_min_weight(0x10);
+    ///
+    /// // The light touch is filtered out
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 0);
+    /// assert!(data.primary().is_none());
+    ///
+    /// // The heavier one is reported as a new touch
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!(data.points[0].status, TouchStatus::Touch);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_min_weight(&mut self, min_weight: u8) {
+        self.state.min_weight = min_weight;
+    }
+
+    /// Ignore touches near the panel edges
+    ///
+    /// Points within `margin` of any edge are treated as not touching, which
+    /// rejects accidental palm and grip contact along the bezel. The margin is
+    /// in display pixels and only takes effect once [`Self::set_scaling`] has
+    /// set the display size, since the raw range rarely matches the panel's
+    /// actual edges. The default of 0 disables edge rejection.
+    ///
+    /// # Arguments
+    /// * `margin` - Width of the rejected border on each edge
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // One touch at (9, 200), inside the margin
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x80, 0x09, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // One touch at (10, 200), just inside the active area
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x80, 0x0A, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // One touch at (310, 200), inside the right-hand margin
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x36, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// // A 320x480 panel that reports coordinates 1:1
+    /// touch.set_scaling(319, 479, 320, 480);
+    /// touch.set_edge_margin(10);
+    ///
+    /// assert!(touch.scan().unwrap().is_empty());
+    /// assert_eq!(touch.scan().unwrap().points[0].x, 10);
+    /// assert!(touch.scan().unwrap().is_empty());
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_edge_margin(&mut self, margin: u16) {
+        self.state.edge_margin = margin;
+    }
+
+    /// Suppress small movements of a finger that is held still
+    ///
+    /// A continuing point keeps its last reported position until it has moved
+    /// more than `counts` along either axis from that position, so sensor
+    /// jitter doesn't turn into a stream of `Move` events. The deadband is in
+    /// reported coordinates, like [`Self::set_edge_margin`]. New touches are
+    /// always reported where they land. The default of 0 reports every change.
+    ///
+    /// # Arguments
+    /// * `counts` - Largest movement that is ignored
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // A finger at (300, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // Jitter to (301, 201)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2D, 0x00, 0xC9, 0x00, 0x00],
+    ///     ),
+    ///     // A real move to (305, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x31, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_move_deadband(2);
+    ///
+    /// let first = touch.scan().unwrap();
+    ///
+    /// // The jitter produces no move
+    /// let jitter = touch.scan().unwrap();
+    /// assert_eq!((jitter.points[0].x, jitter.points[0].y), (300, 200));
+    /// assert_eq!(jitter.as_events(&first).count(), 0);
+    ///
+    /// let moved = touch.scan().unwrap();
+    /// assert_eq!((moved.points[0].x, moved.points[0].y), (305, 200));
+    /// assert_eq!(moved.as_events(&jitter).count(), 1);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_move_deadband(&mut self, counts: u16) {
+        self.state.move_deadband = counts;
+    }
+
+    /// Confirm each touch across several reads before reporting it
+    ///
+    /// `scan()` reads the touch registers `samples` times back to back and
+    /// only reports a point that is present in every read, matched by touch
+    /// ID, with its coordinates averaged across the reads. A point missing
+    /// from any read is treated as not touching. This trades latency for
+    /// rejecting single-read glitches. The default of 1 reads once; 0 is
+    /// treated the same.
+    ///
+    /// Each extra sample costs another burst read of `TD_STATUS` and touch
+    /// point 1 (8 bytes on the bus), plus touch point 2's registers (7 bytes)
+    /// when two touches are reported: roughly 0.25ms per sample at 400kHz, or
+    /// 1ms at 100kHz. Only `scan()` and `scan_at()` take multiple samples.
+    ///
+    /// # Arguments
+    /// * `samples` - Number of reads combined by each scan
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // A finger at (300, 200), then at (302, 204)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2E, 0x00, 0xCC, 0x00, 0x00],
+    ///     ),
+    ///     // A glitch that only shows up in one of two reads
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00; 7]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_confirm_samples(2);
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (301, 202));
+    ///
+    /// assert!(touch.scan().unwrap().is_empty());
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_confirm_samples(&mut self, samples: u8) {
+        self.state.confirm_samples = samples;
+    }
+
+    /// Set the cadence used by [`Self::poll`]
+    pub fn set_poll_scheduler(&mut self, scheduler: PollScheduler) {
+        self.scheduler = scheduler;
+    }
+
+    /// Wait for the next scan, scan, and return the frame if it changed
+    ///
+    /// Waits for the [`PollScheduler`]'s current interval, fast while the
+    /// panel is touched and slow once it is idle, then calls [`Self::scan`]
+    /// and returns `Some` only if the frame differs from the previous one.
+    /// Calling this in a loop gives a complete power-aware polling loop.
+    ///
+    /// This blocks for the whole interval, so only use it where nothing
+    /// else needs to run in between.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal::delay::DelayNs;
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR, TouchStatus};
+    ///
+    /// # struct MockDelay;
+    /// # impl DelayNs for MockDelay {
+    /// #     fn delay_ns(&mut self, _ns: u32) {}
+    /// # }
+    /// let touched = vec![0x01, 0x80, 0x64, 0x00, 0xC8, 0x00, 0x00];
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touched.clone()),
+    ///     // The same finger, held still
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touched.clone()),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touched),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// let mut delay = MockDelay;
+    ///
+    /// // The press and the switch to a held touch are reported
+    /// let data = touch.poll(&mut delay).unwrap().unwrap();
+    /// assert_eq!(data.points[0].status, TouchStatus::Touch);
+    /// let data = touch.poll(&mut delay).unwrap().unwrap();
+    /// assert_eq!(data.points[0].status, TouchStatus::Stream);
+    ///
+    /// // An unchanged frame isn't
+    /// assert!(touch.poll(&mut delay).unwrap().is_none());
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn poll<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<TouchData>, Error<I2C::Error>> {
+        delay.delay_ms(self.scheduler.interval_ms());
+        let prev = self.state.frame();
+        let data = self.scan()?;
+        self.scheduler.update(&data);
+        Ok((data != prev).then_some(data))
+    }
+
+    /// Delay releases until a point has been missing for several scans
+    ///
+    /// A point that disappears is kept as `Stream` at its last position for
+    /// up to `frames` consecutive scans before it is reported as `Release`,
+    /// smoothing over momentary contact dropouts that would otherwise show up
+    /// as Touch/Release flicker. If the point comes back in time it simply
+    /// continues. The default of 0 disables debouncing.
+    ///
+    /// # Arguments
+    /// * `frames` - Number of scans a point may be missing before it is released
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TouchStatus, I2C_ADDR};
+    ///
+    /// let touching = vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00];
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touching.clone()),
+    ///     // The contact drops out for a single scan
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00; 7]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touching),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_release_debounce(1);
+    ///
+    /// assert_eq!(touch.scan().unwrap().points[0].status, TouchStatus::Touch);
+    ///
+    /// // The dropout is reported as continued contact at the last position
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.points[0].status, TouchStatus::Stream);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (300, 200));
+    ///
+    /// // No new press is reported when the contact comes back
+    /// assert_eq!(touch.scan().unwrap().points[0].status, TouchStatus::Stream);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_release_debounce(&mut self, frames: u8) {
+        self.state.release_debounce = frames;
+    }
+
+    /// Collapse every scan to a single logical touch point
+    ///
+    /// For UIs that expect one pointer. The first finger to touch down becomes
+    /// the primary point and is always reported in slot 0, whichever hardware
+    /// slot it occupies; slot 1 stays released. Other fingers are ignored until
+    /// the primary lifts. A finger that was already down when the primary
+    /// lifted is not promoted, since that would make the pointer jump; it is
+    /// picked up the next time a finger touches down. Off by default.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to report only the primary point
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TouchStatus, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // First finger down with touch ID 1, at (100, 100)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x80, 0x64, 0x10, 0x64, 0x00, 0x00],
+    ///     ),
+    ///     // Second finger down with touch ID 0, at (300, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x02, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // The first finger has moved to (110, 100)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x09],
+    ///         vec![0x80, 0x6E, 0x10, 0x64, 0x00, 0x00],
+    ///     ),
+    ///     // The first finger lifts, the second stays down
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_single_touch(true);
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (100, 100));
+    ///
+    /// // The second finger is ignored; the pointer stays on the first
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!(data.points[0].status, TouchStatus::Stream);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (110, 100));
+    ///
+    /// // The first finger's lift ends the stream at its last position
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 0);
+    /// assert_eq!(data.points[0].status, TouchStatus::Release);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (110, 100));
+    ///
+    /// // The finger that stayed down doesn't take over
+    /// assert_eq!(touch.scan().unwrap().touch_count, 0);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_single_touch(&mut self, enabled: bool) {
+        self.state.set_single_touch(enabled);
+    }
+
+    /// Limit how many touch points scans read and report
+    ///
+    /// With a limit of 1, only the first point the controller reports is
+    /// used: touch point 2's registers are never read, saving a bus
+    /// transaction whenever two fingers are down, and a second finger is
+    /// ignored. The point keeps the slot of its hardware ID; combine with
+    /// [`Self::set_single_touch`] to always report it in slot 0. A limit of 2,
+    /// the default, restores full two-point scanning.
+    ///
+    /// # Arguments
+    /// * `points` - Maximum number of touch points, 1 or 2
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if `points` is not 1 or 2, leaving the limit unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{Error, FT6336U, I2C_ADDR};
+    ///
+    /// // Two touches are reported, but point 2's registers are never read
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x02],
+    ///     vec![0x02, 0x80, 0x64, 0x00, 0x64, 0x00, 0x00],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert!(matches!(touch.set_max_points(3), Err(Error::InvalidData)));
+    /// touch.set_max_points(1).unwrap();
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (100, 100));
+    /// assert!(!data.points[1].is_active());
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_max_points(&mut self, points: u8) -> Result<(), Error<I2C::Error>> {
+        if !(1..=MAX_TOUCH_POINTS as u8).contains(&points) {
+            return Err(Error::InvalidData);
+        }
+        self.max_points = points;
+        Ok(())
+    }
+
+    /// Linearly rescale raw coordinates onto a display's pixel range
+    ///
+    /// Every scan maps raw X from `0..=src_max_x` onto `0..dst_w` and raw Y
+    /// from `0..=src_max_y` onto `0..dst_h`, using integer math. Unlike
+    /// clamping, this stretches the full sensor range across the display, so
+    /// the sensor's maximum lands on the last pixel. Raw values above the
+    /// source maximum are treated as the maximum.
+    ///
+    /// With the `i16-coords` feature, `dst_w` and `dst_h` are clamped to
+    /// 32768, the largest size whose pixels all fit an `i16`, so scaled
+    /// coordinates never wrap negative.
+    ///
+    /// # Arguments
+    /// * `src_max_x` - Largest raw X coordinate the sensor reports (e.g. 4095)
+    /// * `src_max_y` - Largest raw Y coordinate the sensor reports
+    /// * `dst_w` - Display width in pixels
+    /// * `dst_h` - Display height in pixels
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // One touch at raw (2048, 4095)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x88, 0x00, 0x0F, 0xFF, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_scaling(4095, 4095, 320, 240);
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (160, 239));
+    ///
+    /// i2c.done();
+    /// ```
+    ///
+    /// With `i16-coords`, an oversized display is clamped:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "i16-coords")]
+    /// # {
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// // One touch at raw (4095, 4095)
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x02],
+    ///     vec![0x01, 0x8F, 0xFF, 0x0F, 0xFF, 0x00, 0x00],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_scaling(4095, 4095, 40000, 40000);
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (32760, 32760));
+    ///
+    /// i2c.done();
+    /// # }
+    /// ```
+    pub fn set_scaling(&mut self, src_max_x: u16, src_max_y: u16, dst_w: u16, dst_h: u16) {
+        self.state.scaling = Some(Scaling::new(src_max_x, src_max_y, dst_w, dst_h));
+    }
+
+    /// Install or remove a hook that sees every register transfer
+    ///
+    /// The hook is called after each successful read or write with the
+    /// register address and the bytes transferred. Failed transfers are
+    /// reported through the returned error instead. Pass `None` to remove it.
+    ///
+    /// # Arguments
+    /// * `trace` - Function called with each [`TraceEvent`], or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::sync::atomic::{AtomicU8, Ordering};
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TraceEvent, I2C_ADDR};
+    ///
+    /// static LAST_ADDR: AtomicU8 = AtomicU8::new(0);
+    ///
+    /// fn trace(event: TraceEvent) {
+    ///     LAST_ADDR.store(event.addr, Ordering::Relaxed);
+    /// }
+    ///
+    /// let expectations = [Transaction::write_read(I2C_ADDR, vec![0x80], vec![22])];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_trace(Some(trace));
+    ///
+    /// touch.read_touch_threshold().unwrap();
+    /// assert_eq!(LAST_ADDR.load(Ordering::Relaxed), 0x80);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_trace(&mut self, trace: Option<TraceFn>) {
+        self.trace = trace;
+    }
+
+    /// Select whether the grouped register readers rely on address auto-increment
+    ///
+    /// By default the readers listed below fetch consecutive registers in a
+    /// single transaction, relying on the controller to auto-increment the
+    /// register address. Some I2C setups don't honor this, so the bytes after
+    /// the first come back wrong. Disabling auto-increment makes these readers
+    /// issue one transaction per register, which costs extra bus round trips
+    /// but always addresses the intended register.
+    ///
+    /// Only these methods honor the setting:
+    /// - [`Self::read_library_version`]
+    /// - `read_gesture_params()`
+    /// - [`Self::device_info`]
+    /// - [`Self::read_panel_resolution`]
+    ///
+    /// [`Self::read_block`], the scans and the touch coordinate readers always
+    /// use a single burst read.
+    ///
+    /// # Arguments
+    /// * `enabled` - `true` for one combined read (default), `false` for one read per byte
+    pub fn set_auto_increment(&mut self, enabled: bool) {
+        self.auto_increment = enabled;
+    }
+
+    /// Select the byte order of the touch coordinate registers
+    ///
+    /// The default, [`ByteOrder::BigEndian`], matches the datasheet. Clone
+    /// firmware that sends each coordinate low byte first can be read with
+    /// [`ByteOrder::LittleEndian`] instead. Applies to every scan method and
+    /// to the single-coordinate reads such as `read_touch1_x`.
+    ///
+    /// # Arguments
+    /// * `order` - Order of each coordinate's two registers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{ByteOrder, FT6336U, I2C_ADDR};
+    ///
+    /// // One touch, point 1 registers: 0x81 0x2C 0x00 0x08
+    /// let report = vec![0x01, 0x81, 0x2C, 0x00, 0x08, 0x00, 0x00];
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], report.clone()),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], report),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// // Datasheet order: X = 0x12C, Y = 0x008
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (300, 8));
+    ///
+    /// // Swapped pairs: X = 0xC81, Y = 0x800
+    /// touch.set_coord_byte_order(ByteOrder::LittleEndian);
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (0xC81, 0x800));
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_coord_byte_order(&mut self, order: ByteOrder) {
+        self.byte_order = order;
+    }
+
+    /// Get the gesture mode last written with `write_g_mode`
+    ///
+    /// This reflects the driver's record of the last successful write, not a
+    /// fresh read of the device, so it costs no bus traffic. If the controller is
+    /// reset or reconfigured by something else, the value may be stale. Before
+    /// the first write it holds the datasheet power-on default,
+    /// [`GestureMode::Trigger`].
+    pub fn cached_gesture_mode(&self) -> GestureMode {
+        self.gesture_mode
+    }
+
+    /// Get the control mode last written with `write_ctrl_mode`
+    ///
+    /// Like [`Self::cached_gesture_mode`], this is the last written value rather
+    /// than a device read. Before the first write it holds the datasheet
+    /// power-on default, [`CtrlMode::SwitchToMonitor`].
+    pub fn cached_ctrl_mode(&self) -> CtrlMode {
+        self.ctrl_mode
+    }
+
+    /// Maximum number of simultaneous touch points this controller reports
+    ///
+    /// [`MAX_TOUCH_POINTS`] for the FT6336U, unless lowered with
+    /// [`Self::set_max_points`]. Provided so code that is generic over touch
+    /// controllers can size its buffers without hard-coding it.
+    pub fn max_touch_points(&self) -> usize {
+        self.max_points as usize
+    }
+
+    /// Wait until the controller has booted and reports the expected chip ID
+    ///
+    /// After power-up or reset the registers read as zero until the controller
+    /// is ready. This polls the chip ID every [`READY_POLL_MS`] until it reads
+    /// [`CHIP_ID`]. Bus errors while polling are treated as "not ready yet",
+    /// since a booting controller may not acknowledge its address.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used between polls
+    /// * `timeout_ms` - Maximum time to wait, in milliseconds
+    ///
+    /// # Returns
+    /// `Error::Timeout` if the chip ID doesn't match before the timeout elapses
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::delay::NoopDelay;
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// // The chip ID reads 0x00 until the controller has finished booting
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x00]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x00]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.init_ready(&mut NoopDelay::new(), 100).unwrap();
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn init_ready<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut waited = 0;
+        loop {
+            if let Ok(CHIP_ID) = self.read_chip_id() {
+                return Ok(());
+            }
+            if waited >= timeout_ms {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(READY_POLL_MS);
+            waited = waited.saturating_add(READY_POLL_MS);
+        }
+    }
+
+    // =========================================================================
+    // Raw Register Access
+    // =========================================================================
+    //
+    // Everything below this section is built on these three methods. They are
+    // public so register-level code can bypass the typed API entirely; they
+    // are still reported to the trace hook, but they don't update the cached
+    // gesture and control modes or the touch tracking state.
+
+    /// Read a single register
+    ///
+    /// # Arguments
+    /// * `addr` - Register address, e.g. one of the `ADDR_*` constants
+    ///
+    /// # Returns
+    /// The register value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, ADDR_CHIP_ID, ADDR_THRESHOLD, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64]),
+    ///     Transaction::write(I2C_ADDR, vec![0x80, 0x28]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert_eq!(touch.read_register(ADDR_CHIP_ID).unwrap(), 0x64);
+    /// touch.write_register(ADDR_THRESHOLD, 0x28).unwrap();
+    ///
+    /// i2c.done();
+    /// ```
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_register(&mut self, addr: u8) -> Result<u8, Error<I2C::Error>> {
+        let mut buf = [0u8; 1];
+        self.i2c.write_read(I2C_ADDR, &[addr], &mut buf)?;
+        self.trace(addr, false, &buf);
+        Ok(buf[0])
+    }
+
+    /// Write a single register
+    ///
+    /// # Arguments
+    /// * `addr` - Register address, e.g. one of the `ADDR_*` constants
+    /// * `data` - Value to write
+    pub fn write_register(&mut self, addr: u8, data: u8) -> Result<(), Error<I2C::Error>> {
+        self.i2c.write(I2C_ADDR, &[addr, data])?;
+        self.trace(addr, true, &[data]);
+        Ok(())
+    }
+
+    /// Read consecutive registers starting at `addr` in one transaction
+    ///
+    /// Relies on the controller's address auto-increment, regardless of
+    /// [`Self::set_auto_increment`].
+    ///
+    /// # Arguments
+    /// * `addr` - Address of the first register
+    /// * `buf` - Buffer to fill, one byte per register
+    pub fn read_block(&mut self, addr: u8, buf: &mut [u8]) -> Result<(), Error<I2C::Error>> {
+        self.i2c.write_read(I2C_ADDR, &[addr], buf)?;
+        self.trace(addr, false, buf);
+        Ok(())
+    }
+
+    /// Read consecutive registers for a diagnostic dump
+    ///
+    /// Reads `buf.len()` registers starting at `start` with [`Self::read_block`]
+    /// and wraps them in a [`RegisterDump`], whose `Debug` output shows each
+    /// register as a hex `addr=val` pair.
     ///
     /// # Arguments
-    /// * `i2c` - I2C bus instance that implements embedded_hal::i2c::I2c
+    /// * `start` - Address of the first register
+    /// * `buf` - Buffer to fill, one byte per register
     ///
-    /// # Note
-    /// The reset and interrupt pins should be managed by the AW9523B GPIO expander
-    /// or by the calling code before creating this driver instance.
-    pub fn new(i2c: I2C) -> Self {
-        Self {
-            i2c,
-            touch_data: TouchData::default(),
-        }
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64, 0x01])];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let mut buf = [0u8; 2];
+    /// let dump = touch.dump_registers(0xA3, &mut buf).unwrap();
+    /// assert_eq!(
+    ///     format!("{dump:?}"),
+    ///     "[ID_G_CIPHER(0xA3)=0x64, ID_G_MODE(0xA4)=0x01]"
+    /// );
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn dump_registers<'a>(
+        &mut self,
+        start: u8,
+        buf: &'a mut [u8],
+    ) -> Result<RegisterDump<'a>, Error<I2C::Error>> {
+        self.read_block(start, buf)?;
+        Ok(RegisterDump::new(start, buf))
     }
 
     // =========================================================================
     // Private I2C Helper Methods
     // =========================================================================
 
-    /// Read a single byte from a register
-    fn read_byte(&mut self, addr: u8) -> Result<u8, Error<I2C::Error>> {
-        let mut buf = [0u8; 1];
-        self.i2c.write_read(I2C_ADDR, &[addr], &mut buf)?;
-        Ok(buf[0])
+    /// Report a completed transfer to the trace hook, if one is set
+    fn trace(&self, addr: u8, is_write: bool, bytes: &[u8]) {
+        if let Some(trace) = self.trace {
+            trace(TraceEvent {
+                addr,
+                is_write,
+                bytes,
+            });
+        }
     }
 
-    /// Write a single byte to a register
-    fn write_byte(&mut self, addr: u8, data: u8) -> Result<(), Error<I2C::Error>> {
-        self.i2c.write(I2C_ADDR, &[addr, data])?;
-        Ok(())
+    /// Read the touch status and point registers in as few transactions as possible
+    ///
+    /// `TD_STATUS` and touch point 1 are read in one burst; touch point 2's
+    /// registers are only read when two touches are reported.
+    fn read_touch_block(&mut self) -> Result<TouchBlock, Error<I2C::Error>> {
+        let mut buf = [0u8; TOUCH1_BLOCK_LEN];
+        self.read_block(ADDR_TD_STATUS, &mut buf)?;
+
+        let mut block = TouchBlock::parse(&buf, self.byte_order);
+        block.count = block.count.min(self.max_points);
+
+        if block.count == 2 {
+            let mut buf = [0u8; POINT_BLOCK_LEN];
+            self.read_block(ADDR_TOUCH2_X, &mut buf)?;
+            block.points[1] = RawPoint::parse(&buf, self.byte_order);
+        }
+
+        Ok(block)
     }
 
     // =========================================================================
@@ -123,8 +1289,9 @@ where
     ///
     /// # Returns
     /// The device mode (Working or Factory)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_device_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_DEVICE_MODE)?;
+        let val = self.read_register(ADDR_DEVICE_MODE)?;
         Ok((val & 0x70) >> 4)
     }
 
@@ -133,7 +1300,260 @@ where
     /// # Arguments
     /// * `mode` - The desired device mode
     pub fn write_device_mode(&mut self, mode: DeviceMode) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DEVICE_MODE, mode.to_register())
+        self.write_register(ADDR_DEVICE_MODE, mode.to_register())
+    }
+
+    /// Write the device operating mode and confirm the device accepted it
+    ///
+    /// Reads the register back after writing, so a write lost to a bus glitch
+    /// is caught here instead of showing up later as garbage factory data.
+    ///
+    /// # Arguments
+    /// * `mode` - The desired device mode
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if the read-back mode doesn't match `mode`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{DeviceMode, Error, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write(I2C_ADDR, vec![0x00, 0x40]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x00], vec![0x40]),
+    ///     // The second write is lost on the bus
+    ///     Transaction::write(I2C_ADDR, vec![0x00, 0x00]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x00], vec![0x40]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.write_device_mode_verified(DeviceMode::Factory).unwrap();
+    /// let result = touch.write_device_mode_verified(DeviceMode::Working);
+    /// assert!(matches!(result, Err(Error::InvalidData)));
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn write_device_mode_verified(
+        &mut self,
+        mode: DeviceMode,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_device_mode(mode)?;
+        if self.device_mode()? != mode {
+            return Err(Error::InvalidData);
+        }
+        Ok(())
+    }
+
+    /// Read the current device operating mode as a typed value
+    ///
+    /// # Returns
+    /// The device mode, or `Error::InvalidData` if the register holds an unknown value
+    pub fn device_mode(&mut self) -> Result<DeviceMode, Error<I2C::Error>> {
+        let val = self.read_device_mode()?;
+        DeviceMode::from_register(val).ok_or(Error::InvalidData)
+    }
+
+    /// Capture and read raw channel data while in factory mode
+    ///
+    /// Triggers a factory scan, waits for the controller to clear the start-scan
+    /// bit, then reads `buf.len()` bytes from the raw data buffer
+    /// (`ADDR_FACTORY_RAW_DATA`). Each channel is reported as a big-endian
+    /// 16-bit value, so size the buffer at two bytes per channel.
+    ///
+    /// The device must already be in factory mode. The full sequence is:
+    /// 1. `write_device_mode(DeviceMode::Factory)`
+    /// 2. Wait for the controller to switch modes (a few hundred milliseconds)
+    /// 3. `read_factory_raw_data(&mut delay, &mut buf)`
+    /// 4. `write_device_mode(DeviceMode::Working)` to resume touch reporting
+    ///
+    /// The factory registers follow FocalTech's FT6x36 test sequence and are not
+    /// in the public datasheet, so their behavior may vary between firmware builds.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used while waiting for the scan
+    /// * `buf` - Buffer to receive the raw channel data
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if the device is not in factory mode, or
+    /// `Error::Timeout` if the scan doesn't complete within
+    /// [`FACTORY_SCAN_TIMEOUT_MS`]
+    pub fn read_factory_raw_data<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        buf: &mut [u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        if self.device_mode()? != DeviceMode::Factory {
+            return Err(Error::InvalidData);
+        }
+        self.factory_scan(delay, buf)
+    }
+
+    /// Capture and read baseline-subtracted channel data while in factory mode
+    ///
+    /// Like [`Self::read_factory_raw_data`], but selects the difference data
+    /// type first, so each channel reads as its raw value minus the stored
+    /// baseline. An idle, well-calibrated panel reads close to zero on every
+    /// channel, which makes noise and poorly coupled channels easy to spot.
+    /// The raw data type is selected again afterwards, even if the scan fails.
+    ///
+    /// The device must already be in factory mode, following the same
+    /// sequence as [`Self::read_factory_raw_data`].
+    ///
+    /// # Channel Layout
+    ///
+    /// Each channel is a big-endian 16-bit two's complement value, in the
+    /// controller's scan order. The number of channels depends on the panel
+    /// and firmware, so size `out` for the panel in use; only whole channels
+    /// are read.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used while waiting for the scan
+    /// * `out` - Buffer to receive the difference data
+    ///
+    /// # Returns
+    /// The number of bytes written to `out`, `Error::InvalidData` if the
+    /// device is not in factory mode, or `Error::Timeout` if the scan doesn't
+    /// complete within [`FACTORY_SCAN_TIMEOUT_MS`]
+    pub fn read_raw_diff<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        out: &mut [u8],
+    ) -> Result<usize, Error<I2C::Error>> {
+        if self.device_mode()? != DeviceMode::Factory {
+            return Err(Error::InvalidData);
+        }
+
+        let len = out.len() & !1;
+        self.write_register(ADDR_FACTORY_DATA_SELECT, FACTORY_DATA_DIFF)?;
+        let result = self.factory_scan(delay, &mut out[..len]);
+        self.write_register(ADDR_FACTORY_DATA_SELECT, FACTORY_DATA_RAW)?;
+        result.map(|()| len)
+    }
+
+    /// Trigger a factory scan and read `buf.len()` bytes of channel data
+    ///
+    /// Assumes the device is already in factory mode.
+    fn factory_scan<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        buf: &mut [u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        let mode = DeviceMode::Factory.to_register();
+        self.write_register(ADDR_DEVICE_MODE, mode | FACTORY_START_SCAN)?;
+
+        let mut waited = 0;
+        while self.read_register(ADDR_DEVICE_MODE)? & FACTORY_START_SCAN != 0 {
+            if waited >= FACTORY_SCAN_TIMEOUT_MS {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(FACTORY_SCAN_POLL_MS);
+            waited += FACTORY_SCAN_POLL_MS;
+        }
+
+        self.read_block(ADDR_FACTORY_RAW_DATA, buf)
+    }
+
+    /// Recalibrate the panel baseline and store the result
+    ///
+    /// Useful after a cover glass change, assembly, or a large temperature
+    /// swing. Nothing may touch the panel while this runs. The register
+    /// sequence follows FocalTech's auto-calibration procedure for its
+    /// FT5x06/FT6x06 family, which is not part of the FT6336U datasheet:
+    ///
+    /// 1. Write `0x40` to `DEVICE_MODE` (`0x00`) to enter factory mode
+    /// 2. Write [`FACTORY_CMD_CALIBRATE`] (`0x04`) to [`ADDR_FACTORY_CMD`] (`0x02`)
+    /// 3. Poll `DEVICE_MODE` until bits 6:4 read 0, meaning calibration is done
+    /// 4. Enter factory mode again and write [`FACTORY_CMD_STORE_CALIBRATION`]
+    ///    (`0x05`) to [`ADDR_FACTORY_CMD`] to store the new baseline
+    /// 5. Write `0x00` to `DEVICE_MODE` to return to working mode
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used for mode switches and polling
+    ///
+    /// # Returns
+    /// `Error::Timeout` if calibration doesn't finish within
+    /// [`CALIBRATION_TIMEOUT_MS`]. The controller is then put back in working
+    /// mode on a best-effort basis, so touch reporting resumes without a
+    /// reset; the new baseline is not stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::delay::NoopDelay;
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write(I2C_ADDR, vec![0x00, 0x40]),
+    ///     Transaction::write(I2C_ADDR, vec![0x02, 0x04]),
+    ///     // Still calibrating, then back in working mode
+    ///     Transaction::write_read(I2C_ADDR, vec![0x00], vec![0x40]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x00], vec![0x00]),
+    ///     Transaction::write(I2C_ADDR, vec![0x00, 0x40]),
+    ///     Transaction::write(I2C_ADDR, vec![0x02, 0x05]),
+    ///     Transaction::write(I2C_ADDR, vec![0x00, 0x00]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.recalibrate(&mut NoopDelay::new()).unwrap();
+    ///
+    /// i2c.done();
+    /// ```
+    ///
+    /// A calibration that never finishes still restores working mode:
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::delay::NoopDelay;
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{Error, FT6336U, CALIBRATION_POLL_MS, CALIBRATION_TIMEOUT_MS, I2C_ADDR};
+    ///
+    /// let polls = (CALIBRATION_TIMEOUT_MS / CALIBRATION_POLL_MS + 1) as usize;
+    /// let mut expectations = vec![
+    ///     Transaction::write(I2C_ADDR, vec![0x00, 0x40]),
+    ///     Transaction::write(I2C_ADDR, vec![0x02, 0x04]),
+    /// ];
+    /// expectations.extend(
+    ///     std::iter::repeat(Transaction::write_read(I2C_ADDR, vec![0x00], vec![0x40])).take(polls),
+    /// );
+    /// expectations.push(Transaction::write(I2C_ADDR, vec![0x00, 0x00]));
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let result = touch.recalibrate(&mut NoopDelay::new());
+    /// assert!(matches!(result, Err(Error::Timeout)));
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn recalibrate<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), Error<I2C::Error>> {
+        self.write_device_mode(DeviceMode::Factory)?;
+        delay.delay_ms(MODE_SWITCH_MS);
+        self.write_register(ADDR_FACTORY_CMD, FACTORY_CMD_CALIBRATE)?;
+        delay.delay_ms(CALIBRATION_SETTLE_MS);
+
+        let mut waited = 0;
+        while self.read_device_mode()? != DeviceMode::Working as u8 {
+            if waited >= CALIBRATION_TIMEOUT_MS {
+                // Don't leave touch reporting dead; the timeout is the error
+                // that matters, so a failed write here is not reported
+                let _ = self.write_device_mode(DeviceMode::Working);
+                delay.delay_ms(MODE_SWITCH_MS);
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(CALIBRATION_POLL_MS);
+            waited += CALIBRATION_POLL_MS;
+        }
+
+        self.write_device_mode(DeviceMode::Factory)?;
+        delay.delay_ms(MODE_SWITCH_MS);
+        self.write_register(ADDR_FACTORY_CMD, FACTORY_CMD_STORE_CALIBRATION)?;
+        delay.delay_ms(CALIBRATION_SETTLE_MS);
+        self.write_device_mode(DeviceMode::Working)?;
+        delay.delay_ms(CALIBRATION_SETTLE_MS);
+        Ok(())
     }
 
     // =========================================================================
@@ -144,27 +1564,310 @@ where
     ///
     /// # Returns
     /// Gesture ID value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_gesture_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_GESTURE_ID)
+        self.read_register(ADDR_GESTURE_ID)
+    }
+
+    /// Read the current gesture, reporting each gesture only once
+    ///
+    /// The `GEST_ID` register is read-only, so it cannot be cleared after a
+    /// read and some firmwares keep the last gesture latched. Instead, the
+    /// driver remembers the last gesture it returned and reports
+    /// [`Gesture::None`] while the register still holds that same value.
+    /// Two identical gestures in a row are therefore only both reported if the
+    /// register returns to `None` in between.
+    ///
+    /// # Returns
+    /// The newly detected gesture, or `Gesture::None` if nothing new was seen.
+    /// Returns `Error::InvalidData` for unknown gesture codes.
+    #[cfg(feature = "gestures")]
+    pub fn take_gesture(&mut self) -> Result<Gesture, Error<I2C::Error>> {
+        let gesture = Gesture::from_register(self.read_gesture_id()?).ok_or(Error::InvalidData)?;
+        if gesture == self.last_gesture {
+            return Ok(Gesture::None);
+        }
+        self.last_gesture = gesture;
+        Ok(gesture)
+    }
+
+    /// Check whether the controller's firmware implements gesture detection
+    ///
+    /// The controller has no capability register, so this probes for the
+    /// gesture tuning registers instead: `ID_G_RADIAN_VALUE` is read, written
+    /// with its lowest bit flipped, and read back, then the original value is
+    /// restored. Firmware built without gestures doesn't back these registers
+    /// and the probe value doesn't stick.
+    ///
+    /// Limitations:
+    /// - Writable tuning registers don't prove that the firmware actually
+    ///   reports gestures, only that it has the gesture configuration block.
+    /// - The radian value is briefly changed, so call this while the panel is
+    ///   idle, such as right after initialization.
+    /// - If the restoring write fails its error is returned and the register
+    ///   may be left holding the probe value.
+    ///
+    /// # Returns
+    /// `true` if the probe value was read back
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x91], vec![0x0A]),
+    ///     Transaction::write(I2C_ADDR, vec![0x91, 0x0B]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x91], vec![0x0B]),
+    ///     // The original value is put back
+    ///     Transaction::write(I2C_ADDR, vec![0x91, 0x0A]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert!(touch.gestures_supported().unwrap());
+    ///
+    /// i2c.done();
+    /// ```
+    #[cfg(feature = "gestures")]
+    pub fn gestures_supported(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let original = self.read_register(ADDR_RADIAN_VALUE)?;
+        let probe = original ^ 0x01;
+        self.write_register(ADDR_RADIAN_VALUE, probe)?;
+        let readback = self.read_register(ADDR_RADIAN_VALUE);
+        self.write_register(ADDR_RADIAN_VALUE, original)?;
+        Ok(readback? == probe)
     }
 
     /// Read the touch detection status register
     ///
     /// # Returns
     /// Raw TD_STATUS register value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_td_status(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TD_STATUS)
+        self.read_register(ADDR_TD_STATUS)
     }
 
     /// Read the number of detected touch points
     ///
     /// # Returns
     /// Number of touch points (0-2)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch_number(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TD_STATUS)?;
+        let val = self.read_register(ADDR_TD_STATUS)?;
         Ok(val & 0x0F)
     }
 
+    /// Check whether anything is touching the panel
+    ///
+    /// A single-byte read of `TD_STATUS`, cheaper than a full [`Self::scan`].
+    /// The cached touch data is not updated.
+    ///
+    /// # Returns
+    /// `true` if at least one touch point is reported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x01]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert!(!touch.has_touch().unwrap());
+    /// assert!(touch.has_touch().unwrap());
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn has_touch(&mut self) -> Result<bool, Error<I2C::Error>> {
+        Ok(self.read_touch_number()? > 0)
+    }
+
+    /// Read the status behind the controller's interrupt
+    ///
+    /// The FT6336U has no dedicated interrupt flag register: INT reflects the
+    /// touch detection status in `TD_STATUS` ([`ADDR_TD_STATUS`], `0x02`), so
+    /// this reads that register's touch count. A nonzero value after an INT
+    /// edge means the controller raised it; 0 means the edge came from
+    /// elsewhere, such as another device on a shared interrupt line.
+    ///
+    /// Reading does not clear anything. INT is released by the controller
+    /// itself, after its pulse in trigger mode or once the touch ends in
+    /// polling mode. Firmware that leaves the register unpopulated reads back
+    /// counts above 2 (typically `0xFF`), which are reported as 0.
+    ///
+    /// # Returns
+    /// The number of touches reported, 0 to 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x01]),
+    ///     // Another device pulled the shared INT line
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00]),
+    ///     // Firmware that leaves the register unpopulated
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0xFF]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert_eq!(touch.read_interrupt_status().unwrap(), 1);
+    /// assert_eq!(touch.read_interrupt_status().unwrap(), 0);
+    /// assert_eq!(touch.read_interrupt_status().unwrap(), 0);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn read_interrupt_status(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let count = self.read_touch_number()?;
+        Ok(if count as usize > MAX_TOUCH_POINTS {
+            0
+        } else {
+            count
+        })
+    }
+
+    /// Borrow the driver as a digital input that reads high while touched
+    ///
+    /// For frameworks that poll [`InputPin`](embedded_hal::digital::InputPin)s.
+    /// Each read goes through [`Self::has_touch`]. See [`TouchInput`].
+    pub fn as_input_pin(&mut self) -> TouchInput<'_, I2C, HISTORY> {
+        TouchInput::new(self)
+    }
+
+    /// Wait until every finger has lifted
+    ///
+    /// Polls [`Self::has_touch`] every `poll_ms` milliseconds until no touch is
+    /// reported, then marks the cached points as released so the next scan
+    /// reports any new contact as a fresh `Touch`.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used between polls
+    /// * `poll_ms` - Interval between polls, in milliseconds
+    /// * `timeout_ms` - Maximum time to wait, in milliseconds
+    ///
+    /// # Returns
+    /// `Error::Timeout` if a touch is still reported when the timeout elapses,
+    /// in which case the cached points are left as they are
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::delay::NoopDelay;
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // Still touching on the first poll, lifted on the second
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x01]),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.wait_for_release(&mut NoopDelay::new(), 20, 1000).unwrap();
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn wait_for_release<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        poll_ms: u32,
+        timeout_ms: u32,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut waited = 0;
+        while self.has_touch()? {
+            if waited >= timeout_ms {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(poll_ms);
+            waited = waited.saturating_add(poll_ms);
+        }
+        self.state.release_all();
+        Ok(())
+    }
+
+    /// Wait for the INT pin to signal a touch, then scan
+    ///
+    /// The FT6336U pulls INT low while it has a report to read. This polls
+    /// `int` every `poll_ms` milliseconds until it reads low, then behaves
+    /// like [`Self::scan`]. The frame is attributed to
+    /// [`ScanSource::Interrupt`] by [`Self::last_scan_source`].
+    ///
+    /// # Arguments
+    /// * `int` - The controller's active-low interrupt pin
+    /// * `delay` - Delay provider used between polls
+    /// * `poll_ms` - Interval between polls, in milliseconds
+    /// * `timeout_ms` - Maximum time to wait, in milliseconds
+    ///
+    /// # Returns
+    /// The scanned touch data, `Error::Timeout` if INT stays high until the
+    /// timeout elapses, or `Error::Pin` if reading the pin fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::delay::NoopDelay;
+    /// use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{ScanSource, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x02],
+    ///     vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// // INT is idle on the first poll and asserted on the second
+    /// let mut int = PinMock::new(&[
+    ///     PinTransaction::get(State::High),
+    ///     PinTransaction::get(State::Low),
+    /// ]);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let data = touch
+    ///     .wait_for_touch(&mut int, &mut NoopDelay::new(), 5, 1000)
+    ///     .unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!(touch.last_scan_source(), ScanSource::Interrupt);
+    ///
+    /// int.done();
+    /// i2c.done();
+    /// ```
+    pub fn wait_for_touch<P, D>(
+        &mut self,
+        int: &mut P,
+        delay: &mut D,
+        poll_ms: u32,
+        timeout_ms: u32,
+    ) -> Result<TouchData, Error<I2C::Error>>
+    where
+        P: InputPin,
+        D: DelayNs,
+    {
+        let mut waited = 0;
+        while int.is_high().map_err(|_| Error::Pin)? {
+            if waited >= timeout_ms {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(poll_ms);
+            waited = waited.saturating_add(poll_ms);
+        }
+        let data = self.scan()?;
+        self.last_source = ScanSource::Interrupt;
+        Ok(data)
+    }
+
     // =========================================================================
     // Touch Point 1 Methods
     // =========================================================================
@@ -173,28 +1876,31 @@ where
     ///
     /// # Returns
     /// X coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch1_x(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c.write_read(I2C_ADDR, &[ADDR_TOUCH1_X], &mut buf)?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH1_X, &mut buf)?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read Y coordinate of touch point 1
     ///
     /// # Returns
     /// Y coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch1_y(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c.write_read(I2C_ADDR, &[ADDR_TOUCH1_Y], &mut buf)?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH1_Y, &mut buf)?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read event type of touch point 1
     ///
     /// # Returns
     /// Event type (0=down, 1=up, 2=contact)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch1_event(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH1_EVENT)?;
+        let val = self.read_register(ADDR_TOUCH1_EVENT)?;
         Ok(val >> 6)
     }
 
@@ -202,8 +1908,9 @@ where
     ///
     /// # Returns
     /// Touch point ID (0 or 1)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch1_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH1_ID)?;
+        let val = self.read_register(ADDR_TOUCH1_ID)?;
         Ok(val >> 4)
     }
 
@@ -211,16 +1918,18 @@ where
     ///
     /// # Returns
     /// Touch weight value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch1_weight(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TOUCH1_WEIGHT)
+        self.read_register(ADDR_TOUCH1_WEIGHT)
     }
 
     /// Read miscellaneous data for touch point 1
     ///
     /// # Returns
     /// Misc data value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch1_misc(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH1_MISC)?;
+        let val = self.read_register(ADDR_TOUCH1_MISC)?;
         Ok(val >> 4)
     }
 
@@ -232,28 +1941,31 @@ where
     ///
     /// # Returns
     /// X coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch2_x(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c.write_read(I2C_ADDR, &[ADDR_TOUCH2_X], &mut buf)?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH2_X, &mut buf)?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read Y coordinate of touch point 2
     ///
     /// # Returns
     /// Y coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch2_y(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c.write_read(I2C_ADDR, &[ADDR_TOUCH2_Y], &mut buf)?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH2_Y, &mut buf)?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read event type of touch point 2
     ///
     /// # Returns
     /// Event type (0=down, 1=up, 2=contact)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch2_event(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH2_EVENT)?;
+        let val = self.read_register(ADDR_TOUCH2_EVENT)?;
         Ok(val >> 6)
     }
 
@@ -261,8 +1973,9 @@ where
     ///
     /// # Returns
     /// Touch point ID (0 or 1)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch2_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH2_ID)?;
+        let val = self.read_register(ADDR_TOUCH2_ID)?;
         Ok(val >> 4)
     }
 
@@ -270,16 +1983,18 @@ where
     ///
     /// # Returns
     /// Touch weight value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch2_weight(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TOUCH2_WEIGHT)
+        self.read_register(ADDR_TOUCH2_WEIGHT)
     }
 
     /// Read miscellaneous data for touch point 2
     ///
     /// # Returns
     /// Misc data value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch2_misc(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH2_MISC)?;
+        let val = self.read_register(ADDR_TOUCH2_MISC)?;
         Ok(val >> 4)
     }
 
@@ -291,56 +2006,296 @@ where
     ///
     /// # Returns
     /// Threshold value (lower = more sensitive)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_touch_threshold(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_THRESHOLD)
+        self.read_register(ADDR_THRESHOLD)
+    }
+
+    /// Write the touch detection threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Threshold value (lower = more sensitive)
+    pub fn write_touch_threshold(&mut self, threshold: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_THRESHOLD, threshold)
+    }
+
+    /// Raise or lower the touch detection threshold from its current value
+    ///
+    /// Reads the threshold, adds `delta`, and writes the result back, so
+    /// sensitivity can be tuned in steps without knowing the panel's absolute
+    /// value. A positive `delta` makes the panel less sensitive. The result
+    /// saturates at the ends of the register's `0..=255` range instead of
+    /// wrapping.
+    ///
+    /// # Arguments
+    /// * `delta` - Amount to add to the current threshold
+    ///
+    /// # Returns
+    /// The threshold that was written
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x80], vec![0x16]),
+    ///     Transaction::write(I2C_ADDR, vec![0x80, 0x18]),
+    ///     // Saturates at the top of the range
+    ///     Transaction::write_read(I2C_ADDR, vec![0x80], vec![0xF0]),
+    ///     Transaction::write(I2C_ADDR, vec![0x80, 0xFF]),
+    ///     // And at the bottom
+    ///     Transaction::write_read(I2C_ADDR, vec![0x80], vec![0x05]),
+    ///     Transaction::write(I2C_ADDR, vec![0x80, 0x00]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert_eq!(touch.adjust_threshold(2).unwrap(), 0x18);
+    /// assert_eq!(touch.adjust_threshold(100).unwrap(), 0xFF);
+    /// assert_eq!(touch.adjust_threshold(-10).unwrap(), 0x00);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn adjust_threshold(&mut self, delta: i8) -> Result<u8, Error<I2C::Error>> {
+        let threshold = self.read_touch_threshold()?.saturating_add_signed(delta);
+        self.write_touch_threshold(threshold)?;
+        Ok(threshold)
     }
 
     /// Read the filter coefficient
     ///
-    /// # Returns
-    /// Filter coefficient value
-    pub fn read_filter_coefficient(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_FILTER_COE)
+    /// # Returns
+    /// Filter coefficient value
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_filter_coefficient(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_FILTER_COE)
+    }
+
+    /// Write the filter coefficient
+    ///
+    /// # Arguments
+    /// * `coefficient` - Raw filter coefficient value
+    pub fn write_filter_coefficient(&mut self, coefficient: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_FILTER_COE, coefficient)
+    }
+
+    /// Read the filter coefficient as the nearest [`FilterStrength`]
+    ///
+    /// Any register value is accepted, so vendor-tuned coefficients are
+    /// classified rather than rejected.
+    pub fn filter_strength(&mut self) -> Result<FilterStrength, Error<I2C::Error>> {
+        let val = self.read_filter_coefficient()?;
+        Ok(FilterStrength::from_register(val))
+    }
+
+    /// Set the filter coefficient from a [`FilterStrength`]
+    ///
+    /// # Arguments
+    /// * `strength` - Desired filter strength
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FilterStrength, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write(I2C_ADDR, vec![0x85, 0xF0]),
+    ///     // Firmware tuned by the panel vendor reads back a nearby value
+    ///     Transaction::write_read(I2C_ADDR, vec![0x85], vec![0xE8]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.set_filter_strength(FilterStrength::High).unwrap();
+    /// assert_eq!(touch.filter_strength().unwrap(), FilterStrength::High);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_filter_strength(
+        &mut self,
+        strength: FilterStrength,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_filter_coefficient(strength.to_register())
+    }
+
+    /// Read the control mode register
+    ///
+    /// # Returns
+    /// Control mode value
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_ctrl_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_CTRL)
+    }
+
+    /// Read the control mode as a typed value
+    ///
+    /// # Returns
+    /// The control mode, or `Error::InvalidData` if the register holds an unknown value
+    pub fn ctrl_mode(&mut self) -> Result<CtrlMode, Error<I2C::Error>> {
+        let val = self.read_ctrl_mode()?;
+        CtrlMode::from_register(val).ok_or(Error::InvalidData)
+    }
+
+    /// Write the control mode
+    ///
+    /// # Arguments
+    /// * `mode` - Control mode (KeepActive or SwitchToMonitor)
+    pub fn write_ctrl_mode(&mut self, mode: CtrlMode) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_CTRL, mode as u8)?;
+        self.ctrl_mode = mode;
+        Ok(())
+    }
+
+    /// Read the time period to enter monitor mode
+    ///
+    /// # Returns
+    /// Time period value in seconds
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_time_period_enter_monitor(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_TIME_ENTER_MONITOR)
+    }
+
+    /// Write the time period to enter monitor mode
+    ///
+    /// # Arguments
+    /// * `seconds` - Idle time in seconds before entering monitor mode
+    pub fn write_time_period_enter_monitor(
+        &mut self,
+        seconds: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_TIME_ENTER_MONITOR, seconds)
+    }
+
+    /// Read the active mode report rate
+    ///
+    /// # Returns
+    /// Report rate in Hz
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_active_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_ACTIVE_MODE_RATE)
+    }
+
+    /// Write the active mode report rate
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
+    pub fn write_active_rate(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_ACTIVE_MODE_RATE, rate)
+    }
+
+    /// Read the monitor mode report rate
+    ///
+    /// # Returns
+    /// Report rate in Hz
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_monitor_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_MONITOR_MODE_RATE)
+    }
+
+    /// Write the monitor mode report rate
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
+    pub fn write_monitor_rate(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_MONITOR_MODE_RATE, rate)
     }
 
-    /// Read the control mode register
+    /// Read the active mode report rate, validated
     ///
     /// # Returns
-    /// Control mode value
-    pub fn read_ctrl_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_CTRL)
+    /// Report rate in Hz, or `Error::InvalidData` if it is outside
+    /// [`MIN_REPORT_RATE_HZ`]..=[`MAX_REPORT_RATE_HZ`]
+    pub fn active_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let rate = self.read_active_rate()?;
+        Self::check_report_rate(rate)
     }
 
-    /// Write the control mode
+    /// Write the active mode report rate after validating it
+    ///
+    /// Unlike [`Self::write_active_rate`], rates outside
+    /// [`MIN_REPORT_RATE_HZ`]..=[`MAX_REPORT_RATE_HZ`] are rejected before
+    /// anything is written. A rate of 0 in particular stops touch reporting.
     ///
     /// # Arguments
-    /// * `mode` - Control mode (KeepActive or SwitchToMonitor)
-    pub fn write_ctrl_mode(&mut self, mode: CtrlMode) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_CTRL, mode as u8)
+    /// * `rate` - Report rate in Hz
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if `rate` is out of range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{Error, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write(I2C_ADDR, vec![0x88, 60])];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// touch.write_active_rate_checked(60).unwrap();
+    ///
+    /// // Rejected without touching the bus
+    /// let result = touch.write_active_rate_checked(0);
+    /// assert!(matches!(result, Err(Error::InvalidData)));
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn write_active_rate_checked(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        let rate = Self::check_report_rate(rate)?;
+        self.write_active_rate(rate)
     }
 
-    /// Read the time period to enter monitor mode
+    /// Read the monitor mode report rate, validated
     ///
     /// # Returns
-    /// Time period value in seconds
-    pub fn read_time_period_enter_monitor(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TIME_ENTER_MONITOR)
+    /// Report rate in Hz, or `Error::InvalidData` if it is outside
+    /// [`MIN_REPORT_RATE_HZ`]..=[`MAX_REPORT_RATE_HZ`]
+    pub fn monitor_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let rate = self.read_monitor_rate()?;
+        Self::check_report_rate(rate)
     }
 
-    /// Read the active mode report rate
+    /// Write the monitor mode report rate after validating it
+    ///
+    /// Like [`Self::write_active_rate_checked`], for the monitor mode rate.
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
     ///
     /// # Returns
-    /// Report rate in Hz
-    pub fn read_active_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_ACTIVE_MODE_RATE)
+    /// `Error::InvalidData` if `rate` is out of range
+    pub fn write_monitor_rate_checked(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        let rate = Self::check_report_rate(rate)?;
+        self.write_monitor_rate(rate)
     }
 
-    /// Read the monitor mode report rate
+    /// Validate a report rate against the accepted range
+    fn check_report_rate(rate: u8) -> Result<u8, Error<I2C::Error>> {
+        if (MIN_REPORT_RATE_HZ..=MAX_REPORT_RATE_HZ).contains(&rate) {
+            Ok(rate)
+        } else {
+            Err(Error::InvalidData)
+        }
+    }
+
+    /// Apply a set of operating parameters
     ///
-    /// # Returns
-    /// Report rate in Hz
-    pub fn read_monitor_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_MONITOR_MODE_RATE)
+    /// Writes every field of `config` to the device, one register at a time.
+    /// If a write fails the error is returned immediately, leaving the
+    /// remaining registers unchanged.
+    ///
+    /// # Arguments
+    /// * `config` - The configuration to apply, e.g. [`Config::recommended`]
+    pub fn configure(&mut self, config: &Config) -> Result<(), Error<I2C::Error>> {
+        self.write_g_mode(config.gesture_mode)?;
+        self.write_ctrl_mode(config.ctrl_mode)?;
+        self.write_touch_threshold(config.threshold)?;
+        self.write_active_rate(config.active_rate)?;
+        self.write_monitor_rate(config.monitor_rate)?;
+        self.write_time_period_enter_monitor(config.time_enter_monitor)
     }
 
     // =========================================================================
@@ -351,96 +2306,198 @@ where
     ///
     /// # Returns
     /// Radian value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_radian_value(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_RADIAN_VALUE)
+        self.read_register(ADDR_RADIAN_VALUE)
     }
 
     /// Write the radian value for gesture detection
     ///
     /// # Arguments
     /// * `val` - Radian value to set
+    #[cfg(feature = "gestures")]
     pub fn write_radian_value(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_RADIAN_VALUE, val)
+        self.write_register(ADDR_RADIAN_VALUE, val)
     }
 
     /// Read the offset for left/right gesture detection
     ///
     /// # Returns
     /// Offset value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_offset_left_right(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_OFFSET_LEFT_RIGHT)
+        self.read_register(ADDR_OFFSET_LEFT_RIGHT)
     }
 
     /// Write the offset for left/right gesture detection
     ///
     /// # Arguments
     /// * `val` - Offset value to set
+    #[cfg(feature = "gestures")]
     pub fn write_offset_left_right(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_OFFSET_LEFT_RIGHT, val)
+        self.write_register(ADDR_OFFSET_LEFT_RIGHT, val)
     }
 
     /// Read the offset for up/down gesture detection
     ///
     /// # Returns
     /// Offset value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_offset_up_down(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_OFFSET_UP_DOWN)
+        self.read_register(ADDR_OFFSET_UP_DOWN)
     }
 
     /// Write the offset for up/down gesture detection
     ///
     /// # Arguments
     /// * `val` - Offset value to set
+    #[cfg(feature = "gestures")]
     pub fn write_offset_up_down(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_OFFSET_UP_DOWN, val)
+        self.write_register(ADDR_OFFSET_UP_DOWN, val)
     }
 
     /// Read the distance for left/right gesture detection
     ///
     /// # Returns
     /// Distance value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_distance_left_right(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_DISTANCE_LEFT_RIGHT)
+        self.read_register(ADDR_DISTANCE_LEFT_RIGHT)
     }
 
     /// Write the distance for left/right gesture detection
     ///
     /// # Arguments
     /// * `val` - Distance value to set
+    #[cfg(feature = "gestures")]
     pub fn write_distance_left_right(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DISTANCE_LEFT_RIGHT, val)
+        self.write_register(ADDR_DISTANCE_LEFT_RIGHT, val)
     }
 
     /// Read the distance for up/down gesture detection
     ///
     /// # Returns
     /// Distance value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_distance_up_down(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_DISTANCE_UP_DOWN)
+        self.read_register(ADDR_DISTANCE_UP_DOWN)
     }
 
     /// Write the distance for up/down gesture detection
     ///
     /// # Arguments
     /// * `val` - Distance value to set
+    #[cfg(feature = "gestures")]
     pub fn write_distance_up_down(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DISTANCE_UP_DOWN, val)
+        self.write_register(ADDR_DISTANCE_UP_DOWN, val)
     }
 
     /// Read the distance for zoom gesture detection
     ///
     /// # Returns
     /// Distance value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_distance_zoom(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_DISTANCE_ZOOM)
+        self.read_register(ADDR_DISTANCE_ZOOM)
     }
 
     /// Write the distance for zoom gesture detection
     ///
     /// # Arguments
     /// * `val` - Distance value to set
+    #[cfg(feature = "gestures")]
     pub fn write_distance_zoom(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DISTANCE_ZOOM, val)
+        self.write_register(ADDR_DISTANCE_ZOOM, val)
+    }
+
+    /// Read all gesture tuning registers
+    ///
+    /// Uses one or six transactions depending on [`Self::set_auto_increment`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x91],
+    ///     vec![0x0A, 0x19, 0x19, 0x20, 0x20, 0x32],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let params = touch.read_gesture_params().unwrap();
+    /// assert_eq!(params.distance_left_right, 0x20);
+    /// assert_eq!(params.distance_zoom, 0x32);
+    ///
+    /// i2c.done();
+    /// ```
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_gesture_params(&mut self) -> Result<GestureParams, Error<I2C::Error>> {
+        let mut buf = [0u8; 6];
+        if self.auto_increment {
+            self.read_block(ADDR_RADIAN_VALUE, &mut buf)?;
+        } else {
+            for (addr, byte) in (ADDR_RADIAN_VALUE..).zip(buf.iter_mut()) {
+                *byte = self.read_register(addr)?;
+            }
+        }
+        Ok(GestureParams {
+            radian_value: buf[0],
+            offset_left_right: buf[1],
+            offset_up_down: buf[2],
+            distance_left_right: buf[3],
+            distance_up_down: buf[4],
+            distance_zoom: buf[5],
+        })
+    }
+
+    /// Write all gesture tuning registers
+    ///
+    /// If a write fails the error is returned immediately, leaving the
+    /// remaining registers unchanged.
+    ///
+    /// # Arguments
+    /// * `params` - Values to write, e.g. from [`Self::read_gesture_params`]
+    #[cfg(feature = "gestures")]
+    pub fn apply_gesture_params(
+        &mut self,
+        params: &GestureParams,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_radian_value(params.radian_value)?;
+        self.write_offset_left_right(params.offset_left_right)?;
+        self.write_offset_up_down(params.offset_up_down)?;
+        self.write_distance_left_right(params.distance_left_right)?;
+        self.write_distance_up_down(params.distance_up_down)?;
+        self.write_distance_zoom(params.distance_zoom)
+    }
+
+    /// Restore the mode and gesture parameters to their power-on defaults
+    ///
+    /// Writes the `DEFAULT_*` constants to the threshold, filter, control,
+    /// monitor timing, report rate and gesture parameter registers, undoing
+    /// runtime tuning without a hardware reset. The interrupt mode is left
+    /// as is. If a write fails the error is returned immediately, leaving the
+    /// remaining registers unchanged.
+    pub fn restore_defaults(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.write_touch_threshold(DEFAULT_THRESHOLD)?;
+        self.write_register(ADDR_FILTER_COE, DEFAULT_FILTER_COE)?;
+        self.write_ctrl_mode(CtrlMode::SwitchToMonitor)?;
+        self.write_time_period_enter_monitor(DEFAULT_TIME_ENTER_MONITOR)?;
+        self.write_active_rate(DEFAULT_ACTIVE_RATE)?;
+        self.write_monitor_rate(DEFAULT_MONITOR_RATE)?;
+        #[cfg(feature = "gestures")]
+        self.apply_gesture_params(&GestureParams::default())?;
+        Ok(())
     }
 
     // =========================================================================
@@ -449,12 +2506,19 @@ where
 
     /// Read the library version from the device
     ///
+    /// Uses one or two transactions depending on [`Self::set_auto_increment`].
+    ///
     /// # Returns
     /// 16-bit library version number
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_library_version(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c
-            .write_read(I2C_ADDR, &[ADDR_LIBRARY_VERSION_H], &mut buf)?;
+        if self.auto_increment {
+            self.read_block(ADDR_LIBRARY_VERSION_H, &mut buf)?;
+        } else {
+            buf[0] = self.read_register(ADDR_LIBRARY_VERSION_H)?;
+            buf[1] = self.read_register(ADDR_LIBRARY_VERSION_L)?;
+        }
         Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
     }
 
@@ -462,16 +2526,53 @@ where
     ///
     /// # Returns
     /// Chip ID (should be 0x64 for FT6336U)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_chip_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_CHIP_ID)
+        self.read_register(ADDR_CHIP_ID)
+    }
+
+    /// Check that the controller still responds with the expected chip ID
+    ///
+    /// Meant to be called periodically in long-running devices. A controller
+    /// that has hung or browned out often still ACKs but reads back `0x00` or
+    /// garbage, which is reported as `Ok(false)`; a controller that stops
+    /// ACKing altogether is reported as a bus error. Either way, a hardware
+    /// reset is the usual recovery.
+    ///
+    /// # Returns
+    /// `true` if the chip ID is [`CHIP_ID`], `false` if it is anything else
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x64]),
+    ///     // A hung controller reads back zeros
+    ///     Transaction::write_read(I2C_ADDR, vec![0xA3], vec![0x00]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// assert!(touch.health_check().unwrap());
+    /// assert!(!touch.health_check().unwrap());
+    ///
+    /// i2c.done();
+    /// ```
+    #[must_use = "the health check result should be acted on"]
+    pub fn health_check(&mut self) -> Result<bool, Error<I2C::Error>> {
+        Ok(self.read_chip_id()? == CHIP_ID)
     }
 
     /// Read the gesture/interrupt mode
     ///
     /// # Returns
     /// G_MODE register value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_g_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_G_MODE)
+        self.read_register(ADDR_G_MODE)
     }
 
     /// Write the gesture/interrupt mode
@@ -479,114 +2580,798 @@ where
     /// # Arguments
     /// * `mode` - Gesture mode (Polling or Trigger)
     pub fn write_g_mode(&mut self, mode: GestureMode) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_G_MODE, mode as u8)
+        self.write_register(ADDR_G_MODE, mode as u8)?;
+        self.gesture_mode = mode;
+        Ok(())
     }
 
     /// Read the power mode
     ///
     /// # Returns
     /// Power mode value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_pwrmode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_POWER_MODE)
+        self.read_register(ADDR_POWER_MODE)
+    }
+
+    /// Put the controller into hibernation
+    ///
+    /// The controller stops scanning and stops answering on the bus until it
+    /// is woken by a reset through its reset pin, so this should be the last
+    /// transfer before powering down.
+    pub fn hibernate(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_POWER_MODE, POWER_MODE_HIBERNATE)
     }
 
     /// Read the firmware ID
     ///
     /// # Returns
     /// Firmware ID value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_firmware_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_FIRMWARE_ID)
+        self.read_register(ADDR_FIRMWARE_ID)
     }
 
     /// Read the Focaltech ID
     ///
     /// # Returns
     /// Focaltech ID value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_focaltech_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_FOCALTECH_ID)
+        self.read_register(ADDR_FOCALTECH_ID)
     }
 
     /// Read the release code ID
     ///
     /// # Returns
     /// Release code ID value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_release_code_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_RELEASE_CODE_ID)
+        self.read_register(ADDR_RELEASE_CODE_ID)
     }
 
     /// Read the device state
     ///
     /// # Returns
     /// Device state value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub fn read_state(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_STATE)
+        self.read_register(ADDR_STATE)
+    }
+
+    /// Read the library version, chip ID, interrupt mode, power mode and
+    /// firmware ID together
+    ///
+    /// The registers from `LIB_VER_H` through `FIRMID` are contiguous, so this
+    /// is a single transaction when auto-increment is enabled (see
+    /// [`Self::set_auto_increment`]), and one read per register otherwise.
+    ///
+    /// # Returns
+    /// The parsed registers, or `Error::InvalidData` if `G_MODE` holds an
+    /// unknown value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, GestureMode, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0xA1],
+    ///     vec![0x30, 0x03, 0x64, 0x01, 0x00, 0x10],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let info = touch.device_info().unwrap();
+    /// assert_eq!(info.library_version, 0x0003);
+    /// assert!(info.is_ft6336u());
+    /// assert_eq!(info.gesture_mode, GestureMode::Trigger);
+    ///
+    /// i2c.done();
+    /// ```
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn device_info(&mut self) -> Result<DeviceInfo, Error<I2C::Error>> {
+        let mut buf = [0u8; SYSTEM_INFO_LEN];
+        if self.auto_increment {
+            self.read_block(ADDR_LIBRARY_VERSION_H, &mut buf)?;
+        } else {
+            for (addr, byte) in (ADDR_LIBRARY_VERSION_H..).zip(buf.iter_mut()) {
+                *byte = self.read_register(addr)?;
+            }
+        }
+        parse_device_info(&buf).ok_or(Error::InvalidData)
+    }
+
+    // =========================================================================
+    // Extended Mode Methods
+    // =========================================================================
+
+    /// Select the register used for glove mode
+    ///
+    /// Glove mode is a FocalTech extension rather than part of the FT6336U
+    /// datasheet. Most firmware builds that support it use
+    /// [`ADDR_GLOVE_MODE`] (`0xC0`), the default; use this if your panel
+    /// vendor documents a different address.
+    ///
+    /// # Arguments
+    /// * `addr` - Glove mode enable register address
+    pub fn set_glove_mode_addr(&mut self, addr: u8) {
+        self.glove_mode_addr = addr;
+    }
+
+    /// Read whether glove (high sensitivity) mode is enabled
+    ///
+    /// Firmware without glove mode may read back any value from this address;
+    /// check with your panel vendor before relying on it.
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_glove_mode(&mut self) -> Result<bool, Error<I2C::Error>> {
+        Ok(self.read_register(self.glove_mode_addr)? != 0)
+    }
+
+    /// Enable or disable glove (high sensitivity) mode
+    ///
+    /// Raises sensitivity so gloved fingers register, at the cost of noise
+    /// immunity. Firmware without glove mode ignores the write or treats the
+    /// address as something else, so only enable it on panels known to
+    /// support it.
+    ///
+    /// # Arguments
+    /// * `enabled` - `true` to enable glove mode
+    pub fn write_glove_mode(&mut self, enabled: bool) -> Result<(), Error<I2C::Error>> {
+        self.write_register(self.glove_mode_addr, enabled as u8)
+    }
+
+    /// Read the panel resolution configured in the firmware, if it has one
+    ///
+    /// Some panel vendors' firmware stores the panel size as big-endian 16-bit
+    /// values starting at [`ADDR_PANEL_RESOLUTION`] (`0x98`/`0x99` for the
+    /// width, `0x9A`/`0x9B` for the height). These registers are not part of
+    /// the FT6336U datasheet, and firmware without them usually reads back
+    /// zeros there. The result can be passed to [`Self::set_scaling`] as the
+    /// source range instead of hard-coding it.
+    ///
+    /// # Returns
+    /// `(width, height)` in touch coordinates, or `None` if the registers read
+    /// as zero or as a size beyond the 12-bit coordinate range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x98], vec![0x01, 0x40, 0x01, 0xE0]),
+    ///     // Firmware without the registers
+    ///     Transaction::write_read(I2C_ADDR, vec![0x98], vec![0x00; 4]),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// if let Some((width, height)) = touch.read_panel_resolution().unwrap() {
+    ///     assert_eq!((width, height), (320, 480));
+    ///     touch.set_scaling(width - 1, height - 1, width, height);
+    /// }
+    /// assert_eq!(touch.read_panel_resolution().unwrap(), None);
+    ///
+    /// i2c.done();
+    /// ```
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub fn read_panel_resolution(&mut self) -> Result<Option<(u16, u16)>, Error<I2C::Error>> {
+        let mut buf = [0u8; RESOLUTION_LEN];
+        if self.auto_increment {
+            self.read_block(ADDR_PANEL_RESOLUTION, &mut buf)?;
+        } else {
+            for (addr, byte) in (ADDR_PANEL_RESOLUTION..).zip(buf.iter_mut()) {
+                *byte = self.read_register(addr)?;
+            }
+        }
+        Ok(parse_resolution(&buf))
     }
 
     // =========================================================================
     // High-Level Scan Method
     // =========================================================================
 
+    /// Read the positions of the currently active touch points
+    ///
+    /// A lighter alternative to [`Self::scan`] for callers that only need to
+    /// know where fingers are right now. Points are returned in report order
+    /// regardless of their hardware ID, with active points first. Points
+    /// lighter than the [`Self::set_min_weight`] threshold are skipped, and
+    /// [`Self::set_scaling`] and [`Self::set_edge_margin`] apply just as they
+    /// do to [`Self::scan`], so both return the same coordinate space. No
+    /// status tracking is done and the driver's cached touch data is left
+    /// untouched.
+    ///
+    /// # Returns
+    /// Up to two `(x, y)` positions; unused slots are `None`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// // One touch at raw (2000, 1000)
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x02],
+    ///     vec![0x01, 0x87, 0xD0, 0x03, 0xE8, 0x00, 0x00],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_scaling(3999, 1999, 320, 240);
+    ///
+    /// // Reported in display pixels, like scan()
+    /// assert_eq!(touch.positions().unwrap(), [Some((160, 120)), None]);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn positions(&mut self) -> Result<Positions, Error<I2C::Error>> {
+        let mut block = self.read_touch_block()?;
+        self.state.filter_weight(&mut block);
+        self.state.map_to_display(&mut block);
+        let mut positions = [None; MAX_TOUCH_POINTS];
+        for (slot, point) in positions
+            .iter_mut()
+            .zip(block.points.iter())
+            .take(block.count as usize)
+        {
+            *slot = Some((point.x as Coord, point.y as Coord));
+        }
+        Ok(positions)
+    }
+
     /// Scan for touch events and update internal touch data
     ///
     /// This is the main method to call periodically or in response to interrupts
     /// to read the current touch state. It reads all touch point data and updates
     /// the internal touch data structure.
     ///
+    /// `TD_STATUS` and touch point 1 are read in a single burst. Touch point 2's
+    /// registers are only read when two touches are reported, so a single-touch
+    /// scan costs one bus transaction.
+    ///
     /// # Returns
     /// TouchData containing the number of touch points and their coordinates/status
+    ///
+    /// # Examples
+    ///
+    /// A single touch only reads `TD_STATUS` and point 1's registers:
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TouchStatus, EVENT_CONTACT, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // TD_STATUS: one touch, then point 1: ID 0 at (300, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!(data.points[0].status, TouchStatus::Touch);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (300, 200));
+    /// assert_eq!(data.points[1].status, TouchStatus::Release);
+    /// assert_eq!(data.points[0].raw_event, EVENT_CONTACT);
+    ///
+    /// i2c.done();
+    /// ```
+    ///
+    /// Two fingers down, with point 2's coordinates read from `P2_XH..P2_YL`:
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TouchStatus, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // TD_STATUS: two touches, then point 1: ID 0 at (300, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x02, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // Point 2: ID 1 at (100, 400)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x09],
+    ///         vec![0x80, 0x64, 0x11, 0x90, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.touch_count, 2);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (300, 200));
+    /// assert_eq!((data.points[1].x, data.points[1].y), (100, 400));
+    /// assert_eq!(data.points[1].status, TouchStatus::Touch);
+    ///
+    /// i2c.done();
+    /// ```
+    ///
+    /// Each finger keeps its own event flag and weight:
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, PointEvent, EVENT_CONTACT, EVENT_PRESS_DOWN, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // Point 1: in contact, weight 0x30
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x02, 0x81, 0x2C, 0x00, 0xC8, 0x30, 0x00],
+    ///     ),
+    ///     // Point 2: just pressed, weight 0x18
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x09],
+    ///         vec![0x00, 0x64, 0x11, 0x90, 0x18, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.points[0].raw_event, EVENT_CONTACT);
+    /// assert_eq!(data.points[0].event(), PointEvent::Contact);
+    /// assert_eq!(data.points[0].weight, 0x30);
+    /// assert_eq!(data.points[1].raw_event, EVENT_PRESS_DOWN);
+    /// assert_eq!(data.points[1].event(), PointEvent::PressDown);
+    /// assert_eq!(data.points[1].weight, 0x18);
+    ///
+    /// i2c.done();
+    /// ```
+    ///
+    /// The reference driver's `PRES_DOWN` and `COORD_UD` are event flag values:
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, PointEvent, COORD_UD, I2C_ADDR, PRES_DOWN};
+    ///
+    /// let expectations = [
+    ///     // Held down (event flag 0b10 in P1_XH)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // Lifted (event flag 0b01) in the last report that includes it
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x41, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.points[0].raw_event, PRES_DOWN);
+    /// assert_eq!(data.points[0].event(), PointEvent::Contact);
+    ///
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!(data.points[0].raw_event, COORD_UD);
+    /// assert_eq!(data.points[0].event(), PointEvent::LiftUp);
+    ///
+    /// i2c.done();
+    /// ```
+    #[must_use = "the scanned touch data is returned, not just cached"]
     pub fn scan(&mut self) -> Result<TouchData, Error<I2C::Error>> {
-        // Read the number of touch points
-        let touch_count = self.read_touch_number()?;
-        self.touch_data.touch_count = touch_count;
-
-        if touch_count == 0 {
-            // No touches - mark both points as released
-            self.touch_data.points[0].status = TouchStatus::Release;
-            self.touch_data.points[1].status = TouchStatus::Release;
-        } else if touch_count == 1 {
-            // Single touch point
-            let id1 = self.read_touch1_id()? as usize;
-            if id1 < 2 {
-                // Update status: if previously released, mark as new touch, otherwise streaming
-                let prev_status = self.touch_data.points[id1].status;
-                self.touch_data.points[id1].status = match prev_status {
-                    TouchStatus::Release => TouchStatus::Touch,
-                    _ => TouchStatus::Stream,
-                };
-
-                // Read coordinates
-                self.touch_data.points[id1].x = self.read_touch1_x()?;
-                self.touch_data.points[id1].y = self.read_touch1_y()?;
-
-                // Mark the other point as released
-                let other_id = (!id1) & 0x01;
-                self.touch_data.points[other_id].status = TouchStatus::Release;
-            }
-        } else {
-            // Two touch points
-            let id1 = self.read_touch1_id()? as usize;
-            if id1 < 2 {
-                let prev_status1 = self.touch_data.points[id1].status;
-                self.touch_data.points[id1].status = match prev_status1 {
-                    TouchStatus::Release => TouchStatus::Touch,
-                    _ => TouchStatus::Stream,
-                };
-                self.touch_data.points[id1].x = self.read_touch1_x()?;
-                self.touch_data.points[id1].y = self.read_touch1_y()?;
-            }
+        let data = self.scan_untracked()?;
+        Ok(self.record(data))
+    }
 
-            let id2 = self.read_touch2_id()? as usize;
-            if id2 < 2 {
-                let prev_status2 = self.touch_data.points[id2].status;
-                self.touch_data.points[id2].status = match prev_status2 {
-                    TouchStatus::Release => TouchStatus::Touch,
-                    _ => TouchStatus::Stream,
-                };
-                self.touch_data.points[id2].x = self.read_touch2_x()?;
-                self.touch_data.points[id2].y = self.read_touch2_y()?;
-            }
+    /// Read and process the touch registers without updating the history
+    fn scan_untracked(&mut self) -> Result<TouchData, Error<I2C::Error>> {
+        let mut confirmation = Confirmation::new(self.read_touch_block()?, self.state.tracking);
+        for _ in 1..self.state.confirm_samples {
+            confirmation.add(&self.read_touch_block()?);
         }
+        Ok(self.state.process(confirmation.finish()))
+    }
+
+    /// Number a scanned frame, append it to the history and hand it back
+    fn record(&mut self, mut data: TouchData) -> TouchData {
+        self.seq = self.seq.wrapping_add(1);
+        data.seq = self.seq;
+        self.last_source = ScanSource::Poll;
+        self.history.push(data);
+        data
+    }
+
+    /// Scan for touch events and track how long each point has been pressed
+    ///
+    /// Behaves like [`Self::scan`], and additionally fills in each point's
+    /// `pressed_ms` from the timestamp of its latest `Touch`. Use it
+    /// consistently in place of `scan()`, since presses that start during an
+    /// untimed scan have no recorded start time.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds from any monotonic source (may wrap)
+    #[must_use = "the scanned touch data is returned, not just cached"]
+    pub fn scan_at(&mut self, now_ms: u32) -> Result<TouchData, Error<I2C::Error>> {
+        self.scan_untracked()?;
+        let data = self.state.track_pressed_time(now_ms);
+        Ok(self.record(data))
+    }
+
+    /// Scan for touch events and measure how long the scan took
+    ///
+    /// Behaves like [`Self::scan`], calling `now` immediately before and after
+    /// it. The crate has no time source of its own, so `now` can read any
+    /// monotonic counter, such as a microsecond timer or a cycle counter; the
+    /// elapsed time is in the same units and handles the counter wrapping.
+    /// Useful for sizing a polling loop to the actual bus timing.
+    ///
+    /// # Arguments
+    /// * `now` - Returns the current value of a monotonic counter
+    ///
+    /// # Returns
+    /// The touch data and the counter difference across the scan
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::cell::Cell;
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00; 7])];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// // A fake microsecond clock that advances 250us per call
+    /// let clock = Cell::new(1_000u32);
+    /// let now = || clock.replace(clock.get() + 250);
+    ///
+    /// let (data, elapsed_us) = touch.scan_timed(now).unwrap();
+    /// assert_eq!(data.touch_count, 0);
+    /// assert_eq!(elapsed_us, 250);
+    ///
+    /// i2c.done();
+    /// ```
+    #[must_use = "the scanned touch data is returned, not just cached"]
+    pub fn scan_timed(
+        &mut self,
+        mut now: impl FnMut() -> u32,
+    ) -> Result<(TouchData, u32), Error<I2C::Error>> {
+        let start = now();
+        let data = self.scan()?;
+        Ok((data, now().wrapping_sub(start)))
+    }
+
+    /// Scan for touch events and read the gesture register in one transaction
+    ///
+    /// Reads from `GEST_ID` through touch point 2's registers in a single
+    /// burst, so the gesture and the touch data are captured together. This
+    /// avoids the race where a new gesture arrives between a separate scan and
+    /// gesture read in trigger mode. The touch data is processed exactly as in
+    /// [`Self::scan`], and with [`Self::set_max_points`] set to 1 the burst
+    /// stops before touch point 2's registers.
+    ///
+    /// # Returns
+    /// The touch data and the gesture register at the time of the read.
+    /// Firmware that doesn't populate the gesture register, or reports an
+    /// unknown gesture code, yields `Gesture::None` so the touch data is never lost.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, Gesture, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x01],
+    ///     vec![
+    ///         0x14, // GEST_ID: move right
+    ///         0x01, // TD_STATUS: one touch
+    ///         0x80, 0x64, 0x00, 0xC8, 0x00, 0x00, // point 1: ID 0 at (100, 200)
+    ///         0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // point 2: unused
+    ///     ],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let (data, gesture) = touch.scan_with_gesture().unwrap();
+    /// assert_eq!(gesture, Gesture::MoveRight);
+    /// assert_eq!(data.touch_count, 1);
+    /// assert_eq!((data.points[0].x, data.points[0].y), (100, 200));
+    ///
+    /// i2c.done();
+    ///
+    /// // Limited to one point, the burst stops before point 2's registers
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x01],
+    ///     vec![
+    ///         0x00, // GEST_ID: no gesture
+    ///         0x02, // TD_STATUS: two touches
+    ///         0x80, 0x64, 0x00, 0xC8, 0x00, 0x00, // point 1: ID 0 at (100, 200)
+    ///     ],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    /// touch.set_max_points(1).unwrap();
+    ///
+    /// let (data, _) = touch.scan_with_gesture().unwrap();
+    /// assert_eq!(data.touch_count, 1);
+    /// assert!(!data.points[1].is_active());
+    ///
+    /// i2c.done();
+    /// ```
+    #[cfg(feature = "gestures")]
+    #[must_use = "the scanned touch data is returned, not just cached"]
+    pub fn scan_with_gesture(&mut self) -> Result<(TouchData, Gesture), Error<I2C::Error>> {
+        // With a single point, stop before touch point 2's registers
+        let len = if self.max_points == 1 {
+            1 + TOUCH1_BLOCK_LEN
+        } else {
+            GESTURE_BLOCK_LEN
+        };
+        let mut buf = [0u8; GESTURE_BLOCK_LEN];
+        self.read_block(ADDR_GESTURE_ID, &mut buf[..len])?;
+
+        let gesture = Gesture::from_register(buf[0]).unwrap_or(Gesture::None);
+        let mut block = TouchBlock::parse(&buf[1..len], self.byte_order);
+        block.count = block.count.min(self.max_points);
+        let data = self.state.process(block);
+        Ok((self.record(data), gesture))
+    }
+
+    /// Mark every cached touch point as released without touching the bus
+    ///
+    /// Use this when the application stops tracking touches mid-gesture (for
+    /// example on a focus or screen change) and needs a lift event the hardware
+    /// never delivered. Only the driver's cache is modified: `touch_count` is set
+    /// to 0 and both points become [`TouchStatus::Release`], keeping their last
+    /// coordinates. A finger that is still down will be reported as a fresh
+    /// [`TouchStatus::Touch`] by the next scan.
+    ///
+    /// # Returns
+    /// The synthesized release frame
+    pub fn release_all(&mut self) -> TouchData {
+        let mut data = self.state.release_all();
+        data.seq = self.seq;
+        data
+    }
+
+    /// Call `on_event` for each change between `prev` and the cached frame
+    ///
+    /// A callback alternative to [`TouchData::as_events`]: the cached frame is
+    /// the one returned by the last scan, and events are produced in the same
+    /// order. Nothing is allocated and the bus is not touched.
+    ///
+    /// # Arguments
+    /// * `prev` - The frame to compare against, usually the previous scan result
+    /// * `on_event` - Called once per event
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{Event, TouchData, FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [Transaction::write_read(
+    ///     I2C_ADDR,
+    ///     vec![0x02],
+    ///     vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    /// )];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// let prev = TouchData::default();
+    /// let _ = touch.scan().unwrap();
+    ///
+    /// let mut downs = 0;
+    /// touch.dispatch(&prev, |event| {
+    ///     if let Event::Down { x, y, .. } = event {
+    ///         assert_eq!((x, y), (300, 200));
+    ///         downs += 1;
+    ///     }
+    /// });
+    /// assert_eq!(downs, 1);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn dispatch(&self, prev: &TouchData, on_event: impl FnMut(Event)) {
+        self.state.frame().as_events(prev).for_each(on_event);
+    }
+
+    /// Choose how reported touch points are assigned to slots
+    ///
+    /// With the default [`TrackingMode::HardwareId`] each point goes to the
+    /// slot given by its touch ID, so a finger keeps its slot from press to
+    /// release. Panels whose firmware reports unreliable IDs can switch to
+    /// [`TrackingMode::Positional`], which fills the slots in report order:
+    /// it is immune to ID collisions and renumbering, but a finger may change
+    /// slot when another one lifts, so per-slot events no longer follow one
+    /// finger. Cached points keep their slot across the switch.
+    ///
+    /// # Arguments
+    /// * `mode` - How points are assigned to slots
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, TrackingMode, I2C_ADDR};
+    ///
+    /// // One finger at (300, 200), reported with touch ID 1
+    /// let touching = vec![0x01, 0x81, 0x2C, 0x10, 0xC8, 0x00, 0x00];
+    /// let expectations = [
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touching.clone()),
+    ///     Transaction::write_read(I2C_ADDR, vec![0x02], touching),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// // Stored in the slot of its ID
+    /// let data = touch.scan().unwrap();
+    /// assert!(!data.points[0].is_active());
+    /// assert_eq!((data.points[1].x, data.points[1].y), (300, 200));
+    ///
+    /// // Stored in the first slot, whatever its ID
+    /// touch.set_tracking(TrackingMode::Positional);
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (300, 200));
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn set_tracking(&mut self, mode: TrackingMode) {
+        self.state.tracking = mode;
+    }
+
+    /// Number of scans where both touch points reported the same touch ID
+    ///
+    /// Some firmware reports ID 0 for both points while two fingers are down.
+    /// Scans work around this by storing the points in report order, and
+    /// count each occurrence here so a misbehaving panel can be spotted. The
+    /// count wraps on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // Two touches, point 1: ID 0 at (300, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x02, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     // Point 2 also claims ID 0, at (100, 400)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x09],
+    ///         vec![0x80, 0x64, 0x01, 0x90, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::new(i2c.clone());
+    ///
+    /// // Both fingers are kept, stored by report order
+    /// let data = touch.scan().unwrap();
+    /// assert_eq!((data.points[0].x, data.points[0].y), (300, 200));
+    /// assert_eq!((data.points[1].x, data.points[1].y), (100, 400));
+    /// assert_eq!(touch.id_collision_count(), 1);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn id_collision_count(&self) -> u32 {
+        self.state.id_collisions
+    }
+
+    /// Whether the last scan followed an interrupt or was a plain poll
+    ///
+    /// [`ScanSource::Interrupt`] after [`Self::wait_for_touch`], and
+    /// [`ScanSource::Poll`] after any other scan method. Tracked by the driver
+    /// alone, so no bus traffic is involved. Useful for confirming in the
+    /// field that an interrupt path is firing rather than falling back to
+    /// polling.
+    pub fn last_scan_source(&self) -> ScanSource {
+        self.last_source
+    }
+
+    /// Frames returned by the most recent scans, oldest first
+    ///
+    /// Holds up to `HISTORY` frames, appended by every scan method, so
+    /// velocity and gesture code can look back without keeping a buffer of
+    /// its own. Once full, each scan drops the oldest frame. A driver created
+    /// with [`FT6336U::new`] has a capacity of 0 and always returns an empty
+    /// slice. The history is cleared by a reset but not by
+    /// [`Self::release_all`], which doesn't scan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    /// use ft6336u_driver::{FT6336U, I2C_ADDR};
+    ///
+    /// let expectations = [
+    ///     // One touch, point 1: ID 0 at (100, 200), then (110, 200), then (120, 200)
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x80, 0x64, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x80, 0x6E, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    ///     Transaction::write_read(
+    ///         I2C_ADDR,
+    ///         vec![0x02],
+    ///         vec![0x01, 0x80, 0x78, 0x00, 0xC8, 0x00, 0x00],
+    ///     ),
+    /// ];
+    /// let mut i2c = Mock::new(&expectations);
+    /// let mut touch = FT6336U::<_, 2>::with_history(i2c.clone());
+    ///
+    /// for _ in 0..3 {
+    ///     let _ = touch.scan().unwrap();
+    /// }
+    ///
+    /// // Only the last two frames are kept, oldest first
+    /// assert_eq!(touch.history_len(), 2);
+    /// let xs: Vec<_> = touch.history().iter().map(|data| data.points[0].x).collect();
+    /// assert_eq!(xs, [110, 120]);
+    ///
+    /// i2c.done();
+    /// ```
+    pub fn history(&self) -> &[TouchData] {
+        self.history.as_slice()
+    }
+
+    /// Number of frames currently held in the history
+    ///
+    /// Grows with each scan until it reaches `HISTORY`.
+    pub fn history_len(&self) -> usize {
+        self.history.as_slice().len()
+    }
+
+    /// Forget every frame in the history
+    ///
+    /// Useful when the application starts a new interaction and older frames
+    /// should not feed into velocity or gesture calculations.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl<I2C, const HISTORY: usize> TouchController for FT6336U<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    type Error = Error<I2C::Error>;
+
+    fn scan(&mut self) -> Result<TouchData, Self::Error> {
+        FT6336U::scan(self)
+    }
+
+    fn max_points(&self) -> usize {
+        self.max_touch_points()
+    }
+
+    fn set_threshold(&mut self, threshold: u8) -> Result<(), Self::Error> {
+        self.write_touch_threshold(threshold)
+    }
 
-        Ok(self.touch_data)
+    fn set_report_rate(&mut self, rate: u8) -> Result<(), Self::Error> {
+        self.write_active_rate(rate)
     }
 }
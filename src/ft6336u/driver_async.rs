@@ -4,11 +4,31 @@
 //! for interacting with the FT6336U hardware using async I2C operations.
 //!
 //! This module is only available when the `async` feature is enabled.
-
+//!
+//! The driver has two layers. `read_register`, `write_register` and
+//! `read_block` form the raw layer, which talks to registers by address.
+//! Every other method belongs to the high-level layer built on top of it.
+
+use embedded_hal::digital::OutputPin;
+use embedded_hal::i2c::{Error as _, ErrorKind};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
 use embedded_hal_async::i2c::I2c;
 
+use super::config::Config;
+#[cfg(feature = "gestures")]
+use super::config::GestureParams;
 use super::constants::*;
+use super::controller::TouchController;
+use super::diagnostics::RegisterDump;
 use super::error::Error;
+use super::event::Event;
+use super::history::History;
+use super::parse::*;
+use super::poll::PollScheduler;
+use super::state::{Scaling, ScanState};
+use super::stream::TouchFrames;
+use super::trace::{TraceEvent, TraceFn};
 use super::types::*;
 
 /// FT6336U capacitive touch controller driver with async I2C interface
@@ -76,13 +96,40 @@ use super::types::*;
 /// // Read device information (these will return errors with MockI2c)
 /// // let chip_id = touch.read_chip_id().await.unwrap();
 /// // let firmware_id = touch.read_firmware_id().await.unwrap();
+/// // Or read the identification registers in one transaction:
+/// // let info = touch.device_info().await.unwrap();
 /// # }
 /// ```
-pub struct FT6336U<I2C> {
+pub struct FT6336U<I2C, const HISTORY: usize = 0> {
     /// I2C bus for communicating with the touch controller
     i2c: I2C,
-    /// Cached touch point data from last scan
-    touch_data: TouchData,
+    /// Cached touch data and scan processing settings
+    state: ScanState,
+    /// Last gesture returned by `take_gesture`
+    #[cfg(feature = "gestures")]
+    last_gesture: Gesture,
+    /// Whether multi-byte register reads rely on address auto-increment
+    auto_increment: bool,
+    /// Order of the two registers in each touch coordinate
+    byte_order: ByteOrder,
+    /// Number of touch points scans read and report (1 or 2)
+    max_points: u8,
+    /// Gesture mode from the last successful `write_g_mode`
+    gesture_mode: GestureMode,
+    /// Control mode from the last successful `write_ctrl_mode`
+    ctrl_mode: CtrlMode,
+    /// Hook called after each register transfer, if set
+    trace: Option<TraceFn>,
+    /// Register address used for glove mode
+    glove_mode_addr: u8,
+    /// Cadence used by `poll`
+    scheduler: PollScheduler,
+    /// Frames returned by recent scans, oldest first
+    history: History<HISTORY>,
+    /// Whether the last scan followed an interrupt
+    last_source: ScanSource,
+    /// Sequence number given to the last scanned frame
+    seq: u32,
 }
 
 impl<I2C> FT6336U<I2C>
@@ -96,31 +143,605 @@ where
     ///
     /// # Note
     /// The reset and interrupt pins should be managed by the AW9523B GPIO expander
-    /// or by the calling code before creating this driver instance.
+    /// or by the calling code. [`Self::reset`] runs the reset sequence on any
+    /// `OutputPin`, including an expander pin.
     pub fn new(i2c: I2C) -> Self {
+        Self::with_history(i2c)
+    }
+
+    /// Create a driver whose cached touch state starts from `initial`
+    ///
+    /// For resuming after the application restarts while the controller keeps
+    /// running: seeding the cache with the last known frame means a finger
+    /// that is still down is reported as a continuing `Stream` by the first
+    /// scan, not as a new `Touch`. Frame numbering continues from
+    /// `initial.seq`. Press timing for `scan_at` restarts, so `pressed_ms`
+    /// counts from the first timed scan.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    /// * `initial` - The touch data to prime the cache with, usually the last
+    ///   frame scanned before the restart
+    pub fn new_with_data(i2c: I2C, initial: TouchData) -> Self {
+        let mut touch = Self::new(i2c);
+        touch.state.touch_data = initial;
+        touch.seq = initial.seq;
+        touch
+    }
+
+    /// Create a driver and verify that an FT6336U is on the bus
+    ///
+    /// The fallible counterpart to [`Self::new`]: the chip ID register is read
+    /// immediately, so wiring and address problems show up at construction
+    /// rather than at the first scan. The controller must already be out of
+    /// reset; see [`Self::init_ready`] for waiting on a booting device.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    ///
+    /// # Returns
+    /// `Error::NotPresent` if nothing acknowledges the address,
+    /// `Error::WrongChipId` if the chip ID is not [`CHIP_ID`], or
+    /// `Error::I2c` for any other bus error
+    pub async fn open(i2c: I2C) -> Result<Self, Error<I2C::Error>> {
+        let mut touch = Self::new(i2c);
+        match touch.read_register(ADDR_CHIP_ID).await {
+            Ok(CHIP_ID) => Ok(touch),
+            Ok(id) => Err(Error::WrongChipId(id)),
+            Err(Error::I2c(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {
+                Err(Error::NotPresent)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a driver, verify the chip, and apply a configuration
+    ///
+    /// The one-call bring-up path: [`Self::open`] followed by
+    /// [`Self::configure`]. The controller must already be out of reset. On
+    /// any error the driver is dropped and the error returned, so a
+    /// half-configured driver is never handed out, although registers written
+    /// before a failed write keep their new values on the device.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    /// * `config` - The configuration to apply, e.g. [`Config::recommended`]
+    ///
+    /// # Returns
+    /// The errors of [`Self::open`], or `Error::I2c` if a configuration
+    /// write fails
+    pub async fn with_config(i2c: I2C, config: &Config) -> Result<Self, Error<I2C::Error>> {
+        let mut touch = Self::open(i2c).await?;
+        touch.configure(config).await?;
+        Ok(touch)
+    }
+}
+
+impl<I2C, const HISTORY: usize> FT6336U<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    /// Create a driver that keeps the last `HISTORY` scanned frames
+    ///
+    /// Identical to [`FT6336U::new`], except every scan also appends the
+    /// returned frame to a fixed-capacity history, readable through
+    /// [`Self::history`]. The buffer lives inside the driver, so no
+    /// allocation is involved.
+    ///
+    /// # Arguments
+    /// * `i2c` - I2C bus instance
+    pub fn with_history(i2c: I2C) -> Self {
         Self {
             i2c,
-            touch_data: TouchData::default(),
+            state: ScanState::default(),
+            #[cfg(feature = "gestures")]
+            last_gesture: Gesture::None,
+            auto_increment: true,
+            byte_order: ByteOrder::BigEndian,
+            max_points: MAX_TOUCH_POINTS as u8,
+            gesture_mode: GestureMode::Trigger,
+            ctrl_mode: CtrlMode::SwitchToMonitor,
+            trace: None,
+            glove_mode_addr: ADDR_GLOVE_MODE,
+            scheduler: PollScheduler::default(),
+            history: History::new(),
+            last_source: ScanSource::Poll,
+            seq: 0,
+        }
+    }
+
+    /// Reset the controller using its reset pin
+    ///
+    /// Drives `rst` low for [`RESET_LOW_MS`], releases it, then waits
+    /// [`RESET_READY_MS`] for the controller to boot before returning. The
+    /// driver's cached touch state and modes are reset to their power-on
+    /// defaults, since the controller has forgotten any previous configuration.
+    ///
+    /// `rst` can be any [`OutputPin`], including a pin on a GPIO expander such
+    /// as the AW9523B, as long as its HAL implements the trait.
+    ///
+    /// # Arguments
+    /// * `rst` - The controller's active-low reset pin
+    /// * `delay` - Async delay provider
+    ///
+    /// # Returns
+    /// `Error::Pin` if driving the reset pin fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use core::convert::Infallible;
+    /// # struct MockI2c;
+    /// # impl embedded_hal::i2c::ErrorType for MockI2c {
+    /// #     type Error = Infallible;
+    /// # }
+    /// # impl I2c for MockI2c {
+    /// #     async fn write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn read(&mut self, _: u8, _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn transaction(&mut self, _: u8, _: &mut [embedded_hal_async::i2c::Operation<'_>]) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # struct MockPin;
+    /// # impl embedded_hal::digital::ErrorType for MockPin {
+    /// #     type Error = Infallible;
+    /// # }
+    /// # impl embedded_hal::digital::OutputPin for MockPin {
+    /// #     fn set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// #     fn set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # struct MockDelay;
+    /// # impl embedded_hal_async::delay::DelayNs for MockDelay {
+    /// #     async fn delay_ns(&mut self, _: u32) {}
+    /// # }
+    /// # async fn example() {
+    /// # let (i2c, mut rst, mut delay) = (MockI2c, MockPin, MockDelay);
+    /// use ft6336u_driver::{FT6336U, GestureMode};
+    ///
+    /// let mut touch = FT6336U::new(i2c);
+    ///
+    /// touch.reset(&mut rst, &mut delay).await.unwrap();
+    /// touch.write_g_mode(GestureMode::Trigger).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn reset<P, D>(&mut self, rst: &mut P, delay: &mut D) -> Result<(), Error<I2C::Error>>
+    where
+        P: OutputPin,
+        D: DelayNs,
+    {
+        self.reset_with_timing(rst, delay, RESET_LOW_MS, RESET_READY_MS)
+            .await
+    }
+
+    /// Reset the controller with custom reset timing
+    ///
+    /// Like [`Self::reset`], with the low pulse and boot wait given
+    /// explicitly. Useful when the reset line has extra capacitance, or is
+    /// driven through a GPIO expander such as the AW9523B whose pin writes are
+    /// themselves slow I2C transactions.
+    ///
+    /// # Arguments
+    /// * `rst` - The controller's active-low reset pin
+    /// * `delay` - Async delay provider
+    /// * `low_ms` - How long to hold reset low, in milliseconds
+    /// * `ready_ms` - How long to wait after releasing reset, in milliseconds
+    ///
+    /// # Returns
+    /// `Error::Pin` if driving the reset pin fails
+    pub async fn reset_with_timing<P, D>(
+        &mut self,
+        rst: &mut P,
+        delay: &mut D,
+        low_ms: u32,
+        ready_ms: u32,
+    ) -> Result<(), Error<I2C::Error>>
+    where
+        P: OutputPin,
+        D: DelayNs,
+    {
+        rst.set_low().map_err(|_| Error::Pin)?;
+        delay.delay_ms(low_ms).await;
+        rst.set_high().map_err(|_| Error::Pin)?;
+        delay.delay_ms(ready_ms).await;
+
+        self.state.touch_data = TouchData::default();
+        self.history.clear();
+        #[cfg(feature = "gestures")]
+        {
+            self.last_gesture = Gesture::None;
+        }
+        self.gesture_mode = GestureMode::Trigger;
+        self.ctrl_mode = CtrlMode::SwitchToMonitor;
+        Ok(())
+    }
+
+    /// Consume the driver and return the I2C bus
+    ///
+    /// Use this to repurpose the bus, for example before entering deep sleep.
+    /// The controller itself is left in whatever state it was in.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Borrow the I2C bus for transactions the driver doesn't provide
+    ///
+    /// Transfers made this way bypass the driver entirely: they are not seen
+    /// by the trace hook, and writes that change the controller's mode or
+    /// settings are not reflected in cached state such as
+    /// [`Self::cached_gesture_mode`] or the last scanned touch data.
+    pub fn inner_mut(&mut self) -> &mut I2C {
+        &mut self.i2c
+    }
+
+    /// Ignore touches lighter than a minimum weight
+    ///
+    /// Points whose `WEIGHT` register is below `min_weight` are treated as not
+    /// touching by every scan method. This is a cheap way to reject light
+    /// phantom touches, for example near the panel edges. The default of 0
+    /// disables filtering.
+    ///
+    /// # Arguments
+    /// * `min_weight` - Minimum weight for a point to count as touching
+    pub fn set_min_weight(&mut self, min_weight: u8) {
+        self.state.min_weight = min_weight;
+    }
+
+    /// Ignore touches near the panel edges
+    ///
+    /// Points within `margin` of any edge are treated as not touching, which
+    /// rejects accidental palm and grip contact along the bezel. The margin is
+    /// in display pixels and only takes effect once [`Self::set_scaling`] has
+    /// set the display size, since the raw range rarely matches the panel's
+    /// actual edges. The default of 0 disables edge rejection.
+    ///
+    /// # Arguments
+    /// * `margin` - Width of the rejected border on each edge
+    pub fn set_edge_margin(&mut self, margin: u16) {
+        self.state.edge_margin = margin;
+    }
+
+    /// Suppress small movements of a finger that is held still
+    ///
+    /// A continuing point keeps its last reported position until it has moved
+    /// more than `counts` along either axis from that position, so sensor
+    /// jitter doesn't turn into a stream of `Move` events. The deadband is in
+    /// reported coordinates, like [`Self::set_edge_margin`]. New touches are
+    /// always reported where they land. The default of 0 reports every change.
+    ///
+    /// # Arguments
+    /// * `counts` - Largest movement that is ignored
+    pub fn set_move_deadband(&mut self, counts: u16) {
+        self.state.move_deadband = counts;
+    }
+
+    /// Confirm each touch across several reads before reporting it
+    ///
+    /// `scan()` reads the touch registers `samples` times back to back and
+    /// only reports a point that is present in every read, matched by touch
+    /// ID, with its coordinates averaged across the reads. A point missing
+    /// from any read is treated as not touching. This trades latency for
+    /// rejecting single-read glitches. The default of 1 reads once; 0 is
+    /// treated the same.
+    ///
+    /// Each extra sample costs another burst read of `TD_STATUS` and touch
+    /// point 1 (8 bytes on the bus), plus touch point 2's registers (7 bytes)
+    /// when two touches are reported: roughly 0.25ms per sample at 400kHz, or
+    /// 1ms at 100kHz. Only `scan()` and `scan_at()` take multiple samples.
+    ///
+    /// # Arguments
+    /// * `samples` - Number of reads combined by each scan
+    pub fn set_confirm_samples(&mut self, samples: u8) {
+        self.state.confirm_samples = samples;
+    }
+
+    /// Set the cadence used by [`Self::poll`]
+    pub fn set_poll_scheduler(&mut self, scheduler: PollScheduler) {
+        self.scheduler = scheduler;
+    }
+
+    /// Wait for the next scan, scan, and return the frame if it changed
+    ///
+    /// Waits for the [`PollScheduler`]'s current interval, fast while the
+    /// panel is touched and slow once it is idle, then calls [`Self::scan`]
+    /// and returns `Some` only if the frame differs from the previous one.
+    /// Calling this in a loop gives a complete power-aware polling loop.
+    ///
+    /// This awaits the delay for the whole interval, so only use it where nothing
+    /// else needs to run in between.
+    pub async fn poll<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Option<TouchData>, Error<I2C::Error>> {
+        delay.delay_ms(self.scheduler.interval_ms()).await;
+        let prev = self.state.frame();
+        let data = self.scan().await?;
+        self.scheduler.update(&data);
+        Ok((data != prev).then_some(data))
+    }
+
+    /// Delay releases until a point has been missing for several scans
+    ///
+    /// A point that disappears is kept as `Stream` at its last position for
+    /// up to `frames` consecutive scans before it is reported as `Release`,
+    /// smoothing over momentary contact dropouts that would otherwise show up
+    /// as Touch/Release flicker. If the point comes back in time it simply
+    /// continues. The default of 0 disables debouncing.
+    ///
+    /// # Arguments
+    /// * `frames` - Number of scans a point may be missing before it is released
+    pub fn set_release_debounce(&mut self, frames: u8) {
+        self.state.release_debounce = frames;
+    }
+
+    /// Collapse every scan to a single logical touch point
+    ///
+    /// For UIs that expect one pointer. The first finger to touch down becomes
+    /// the primary point and is always reported in slot 0, whichever hardware
+    /// slot it occupies; slot 1 stays released. Other fingers are ignored until
+    /// the primary lifts. A finger that was already down when the primary
+    /// lifted is not promoted, since that would make the pointer jump; it is
+    /// picked up the next time a finger touches down. Off by default.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to report only the primary point
+    pub fn set_single_touch(&mut self, enabled: bool) {
+        self.state.set_single_touch(enabled);
+    }
+
+    /// Limit how many touch points scans read and report
+    ///
+    /// With a limit of 1, only the first point the controller reports is
+    /// used: touch point 2's registers are never read, saving a bus
+    /// transaction whenever two fingers are down, and a second finger is
+    /// ignored. The point keeps the slot of its hardware ID; combine with
+    /// [`Self::set_single_touch`] to always report it in slot 0. A limit of 2,
+    /// the default, restores full two-point scanning.
+    ///
+    /// # Arguments
+    /// * `points` - Maximum number of touch points, 1 or 2
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if `points` is not 1 or 2, leaving the limit unchanged
+    pub fn set_max_points(&mut self, points: u8) -> Result<(), Error<I2C::Error>> {
+        if !(1..=MAX_TOUCH_POINTS as u8).contains(&points) {
+            return Err(Error::InvalidData);
+        }
+        self.max_points = points;
+        Ok(())
+    }
+
+    /// Linearly rescale raw coordinates onto a display's pixel range
+    ///
+    /// Every scan maps raw X from `0..=src_max_x` onto `0..dst_w` and raw Y
+    /// from `0..=src_max_y` onto `0..dst_h`, using integer math. Unlike
+    /// clamping, this stretches the full sensor range across the display, so
+    /// the sensor's maximum lands on the last pixel. Raw values above the
+    /// source maximum are treated as the maximum.
+    ///
+    /// With the `i16-coords` feature, `dst_w` and `dst_h` are clamped to
+    /// 32768, the largest size whose pixels all fit an `i16`, so scaled
+    /// coordinates never wrap negative.
+    ///
+    /// # Arguments
+    /// * `src_max_x` - Largest raw X coordinate the sensor reports (e.g. 4095)
+    /// * `src_max_y` - Largest raw Y coordinate the sensor reports
+    /// * `dst_w` - Display width in pixels
+    /// * `dst_h` - Display height in pixels
+    pub fn set_scaling(&mut self, src_max_x: u16, src_max_y: u16, dst_w: u16, dst_h: u16) {
+        self.state.scaling = Some(Scaling::new(src_max_x, src_max_y, dst_w, dst_h));
+    }
+
+    /// Install or remove a hook that sees every register transfer
+    ///
+    /// The hook is called after each successful read or write with the
+    /// register address and the bytes transferred. Failed transfers are
+    /// reported through the returned error instead. Pass `None` to remove it.
+    ///
+    /// # Arguments
+    /// * `trace` - Function called with each [`TraceEvent`], or `None`
+    pub fn set_trace(&mut self, trace: Option<TraceFn>) {
+        self.trace = trace;
+    }
+
+    /// Select whether the grouped register readers rely on address auto-increment
+    ///
+    /// By default the readers listed below fetch consecutive registers in a
+    /// single transaction, relying on the controller to auto-increment the
+    /// register address. Some I2C setups don't honor this, so the bytes after
+    /// the first come back wrong. Disabling auto-increment makes these readers
+    /// issue one transaction per register, which costs extra bus round trips
+    /// but always addresses the intended register.
+    ///
+    /// Only these methods honor the setting:
+    /// - [`Self::read_library_version`]
+    /// - `read_gesture_params()`
+    /// - [`Self::device_info`]
+    /// - [`Self::read_panel_resolution`]
+    ///
+    /// [`Self::read_block`], the scans and the touch coordinate readers always
+    /// use a single burst read.
+    ///
+    /// # Arguments
+    /// * `enabled` - `true` for one combined read (default), `false` for one read per byte
+    pub fn set_auto_increment(&mut self, enabled: bool) {
+        self.auto_increment = enabled;
+    }
+
+    /// Select the byte order of the touch coordinate registers
+    ///
+    /// The default, [`ByteOrder::BigEndian`], matches the datasheet. Clone
+    /// firmware that sends each coordinate low byte first can be read with
+    /// [`ByteOrder::LittleEndian`] instead. Applies to every scan method and
+    /// to the single-coordinate reads such as `read_touch1_x`.
+    ///
+    /// # Arguments
+    /// * `order` - Order of each coordinate's two registers
+    pub fn set_coord_byte_order(&mut self, order: ByteOrder) {
+        self.byte_order = order;
+    }
+
+    /// Get the gesture mode last written with `write_g_mode`
+    ///
+    /// This reflects the driver's record of the last successful write, not a
+    /// fresh read of the device, so it costs no bus traffic. If the controller is
+    /// reset or reconfigured by something else, the value may be stale. Before
+    /// the first write it holds the datasheet power-on default,
+    /// [`GestureMode::Trigger`].
+    pub fn cached_gesture_mode(&self) -> GestureMode {
+        self.gesture_mode
+    }
+
+    /// Get the control mode last written with `write_ctrl_mode`
+    ///
+    /// Like [`Self::cached_gesture_mode`], this is the last written value rather
+    /// than a device read. Before the first write it holds the datasheet
+    /// power-on default, [`CtrlMode::SwitchToMonitor`].
+    pub fn cached_ctrl_mode(&self) -> CtrlMode {
+        self.ctrl_mode
+    }
+
+    /// Maximum number of simultaneous touch points this controller reports
+    ///
+    /// [`MAX_TOUCH_POINTS`] for the FT6336U, unless lowered with
+    /// [`Self::set_max_points`]. Provided so code that is generic over touch
+    /// controllers can size its buffers without hard-coding it.
+    pub fn max_touch_points(&self) -> usize {
+        self.max_points as usize
+    }
+
+    /// Wait until the controller has booted and reports the expected chip ID
+    ///
+    /// After power-up or reset the registers read as zero until the controller
+    /// is ready. This polls the chip ID every [`READY_POLL_MS`] until it reads
+    /// [`CHIP_ID`]. Bus errors while polling are treated as "not ready yet",
+    /// since a booting controller may not acknowledge its address.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used between polls
+    /// * `timeout_ms` - Maximum time to wait, in milliseconds
+    ///
+    /// # Returns
+    /// `Error::Timeout` if the chip ID doesn't match before the timeout elapses
+    pub async fn init_ready<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut waited = 0;
+        loop {
+            if let Ok(CHIP_ID) = self.read_chip_id().await {
+                return Ok(());
+            }
+            if waited >= timeout_ms {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(READY_POLL_MS).await;
+            waited = waited.saturating_add(READY_POLL_MS);
         }
     }
 
     // =========================================================================
-    // Private I2C Helper Methods
+    // Raw Register Access
     // =========================================================================
+    //
+    // Everything below this section is built on these three methods. They are
+    // public so register-level code can bypass the typed API entirely; they
+    // are still reported to the trace hook, but they don't update the cached
+    // gesture and control modes or the touch tracking state.
 
-    /// Read a single byte from a register
-    async fn read_byte(&mut self, addr: u8) -> Result<u8, Error<I2C::Error>> {
+    /// Read a single register
+    ///
+    /// # Arguments
+    /// * `addr` - Register address, e.g. one of the `ADDR_*` constants
+    ///
+    /// # Returns
+    /// The register value
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_register(&mut self, addr: u8) -> Result<u8, Error<I2C::Error>> {
         let mut buf = [0u8; 1];
         self.i2c.write_read(I2C_ADDR, &[addr], &mut buf).await?;
+        self.trace(addr, false, &buf);
         Ok(buf[0])
     }
 
-    /// Write a single byte to a register
-    async fn write_byte(&mut self, addr: u8, data: u8) -> Result<(), Error<I2C::Error>> {
+    /// Write a single register
+    ///
+    /// # Arguments
+    /// * `addr` - Register address, e.g. one of the `ADDR_*` constants
+    /// * `data` - Value to write
+    pub async fn write_register(&mut self, addr: u8, data: u8) -> Result<(), Error<I2C::Error>> {
         self.i2c.write(I2C_ADDR, &[addr, data]).await?;
+        self.trace(addr, true, &[data]);
         Ok(())
     }
 
+    /// Read consecutive registers starting at `addr` in one transaction
+    ///
+    /// Relies on the controller's address auto-increment, regardless of
+    /// [`Self::set_auto_increment`].
+    ///
+    /// # Arguments
+    /// * `addr` - Address of the first register
+    /// * `buf` - Buffer to fill, one byte per register
+    pub async fn read_block(&mut self, addr: u8, buf: &mut [u8]) -> Result<(), Error<I2C::Error>> {
+        self.i2c.write_read(I2C_ADDR, &[addr], buf).await?;
+        self.trace(addr, false, buf);
+        Ok(())
+    }
+
+    /// Read consecutive registers for a diagnostic dump
+    ///
+    /// Reads `buf.len()` registers starting at `start` with [`Self::read_block`]
+    /// and wraps them in a [`RegisterDump`], whose `Debug` output shows each
+    /// register as a hex `addr=val` pair.
+    ///
+    /// # Arguments
+    /// * `start` - Address of the first register
+    /// * `buf` - Buffer to fill, one byte per register
+    pub async fn dump_registers<'a>(
+        &mut self,
+        start: u8,
+        buf: &'a mut [u8],
+    ) -> Result<RegisterDump<'a>, Error<I2C::Error>> {
+        self.read_block(start, buf).await?;
+        Ok(RegisterDump::new(start, buf))
+    }
+
+    // =========================================================================
+    // Private I2C Helper Methods
+    // =========================================================================
+
+    /// Report a completed transfer to the trace hook, if one is set
+    fn trace(&self, addr: u8, is_write: bool, bytes: &[u8]) {
+        if let Some(trace) = self.trace {
+            trace(TraceEvent {
+                addr,
+                is_write,
+                bytes,
+            });
+        }
+    }
+
+    /// Read the touch status and point registers in as few transactions as possible
+    ///
+    /// `TD_STATUS` and touch point 1 are read in one burst; touch point 2's
+    /// registers are only read when two touches are reported.
+    async fn read_touch_block(&mut self) -> Result<TouchBlock, Error<I2C::Error>> {
+        let mut buf = [0u8; TOUCH1_BLOCK_LEN];
+        self.read_block(ADDR_TD_STATUS, &mut buf).await?;
+
+        let mut block = TouchBlock::parse(&buf, self.byte_order);
+        block.count = block.count.min(self.max_points);
+
+        if block.count == 2 {
+            let mut buf = [0u8; POINT_BLOCK_LEN];
+            self.read_block(ADDR_TOUCH2_X, &mut buf).await?;
+            block.points[1] = RawPoint::parse(&buf, self.byte_order);
+        }
+
+        Ok(block)
+    }
+
     // =========================================================================
     // Device Mode Register Methods
     // =========================================================================
@@ -129,46 +750,458 @@ where
     ///
     /// # Returns
     /// The device mode (Working or Factory)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_device_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_DEVICE_MODE).await?;
+        let val = self.read_register(ADDR_DEVICE_MODE).await?;
         Ok((val & 0x70) >> 4)
     }
 
-    /// Write the device operating mode
+    /// Write the device operating mode
+    ///
+    /// # Arguments
+    /// * `mode` - The desired device mode
+    pub async fn write_device_mode(&mut self, mode: DeviceMode) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_DEVICE_MODE, mode.to_register())
+            .await
+    }
+
+    /// Write the device operating mode and confirm the device accepted it
+    ///
+    /// Reads the register back after writing, so a write lost to a bus glitch
+    /// is caught here instead of showing up later as garbage factory data.
+    ///
+    /// # Arguments
+    /// * `mode` - The desired device mode
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if the read-back mode doesn't match `mode`
+    pub async fn write_device_mode_verified(
+        &mut self,
+        mode: DeviceMode,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_device_mode(mode).await?;
+        if self.device_mode().await? != mode {
+            return Err(Error::InvalidData);
+        }
+        Ok(())
+    }
+
+    /// Read the current device operating mode as a typed value
+    ///
+    /// # Returns
+    /// The device mode, or `Error::InvalidData` if the register holds an unknown value
+    pub async fn device_mode(&mut self) -> Result<DeviceMode, Error<I2C::Error>> {
+        let val = self.read_device_mode().await?;
+        DeviceMode::from_register(val).ok_or(Error::InvalidData)
+    }
+
+    /// Capture and read raw channel data while in factory mode
+    ///
+    /// Triggers a factory scan, waits for the controller to clear the start-scan
+    /// bit, then reads `buf.len()` bytes from the raw data buffer
+    /// (`ADDR_FACTORY_RAW_DATA`). Each channel is reported as a big-endian
+    /// 16-bit value, so size the buffer at two bytes per channel.
+    ///
+    /// The device must already be in factory mode. The full sequence is:
+    /// 1. `write_device_mode(DeviceMode::Factory)`
+    /// 2. Wait for the controller to switch modes (a few hundred milliseconds)
+    /// 3. `read_factory_raw_data(&mut delay, &mut buf)`
+    /// 4. `write_device_mode(DeviceMode::Working)` to resume touch reporting
+    ///
+    /// The factory registers follow FocalTech's FT6x36 test sequence and are not
+    /// in the public datasheet, so their behavior may vary between firmware builds.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used while waiting for the scan
+    /// * `buf` - Buffer to receive the raw channel data
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if the device is not in factory mode, or
+    /// `Error::Timeout` if the scan doesn't complete within
+    /// [`FACTORY_SCAN_TIMEOUT_MS`]
+    pub async fn read_factory_raw_data<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        buf: &mut [u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        if self.device_mode().await? != DeviceMode::Factory {
+            return Err(Error::InvalidData);
+        }
+        self.factory_scan(delay, buf).await
+    }
+
+    /// Capture and read baseline-subtracted channel data while in factory mode
+    ///
+    /// Like [`Self::read_factory_raw_data`], but selects the difference data
+    /// type first, so each channel reads as its raw value minus the stored
+    /// baseline. An idle, well-calibrated panel reads close to zero on every
+    /// channel, which makes noise and poorly coupled channels easy to spot.
+    /// The raw data type is selected again afterwards, even if the scan fails.
+    ///
+    /// The device must already be in factory mode, following the same
+    /// sequence as [`Self::read_factory_raw_data`].
+    ///
+    /// # Channel Layout
+    ///
+    /// Each channel is a big-endian 16-bit two's complement value, in the
+    /// controller's scan order. The number of channels depends on the panel
+    /// and firmware, so size `out` for the panel in use; only whole channels
+    /// are read.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used while waiting for the scan
+    /// * `out` - Buffer to receive the difference data
+    ///
+    /// # Returns
+    /// The number of bytes written to `out`, `Error::InvalidData` if the
+    /// device is not in factory mode, or `Error::Timeout` if the scan doesn't
+    /// complete within [`FACTORY_SCAN_TIMEOUT_MS`]
+    pub async fn read_raw_diff<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        out: &mut [u8],
+    ) -> Result<usize, Error<I2C::Error>> {
+        if self.device_mode().await? != DeviceMode::Factory {
+            return Err(Error::InvalidData);
+        }
+
+        let len = out.len() & !1;
+        self.write_register(ADDR_FACTORY_DATA_SELECT, FACTORY_DATA_DIFF)
+            .await?;
+        let result = self.factory_scan(delay, &mut out[..len]).await;
+        self.write_register(ADDR_FACTORY_DATA_SELECT, FACTORY_DATA_RAW)
+            .await?;
+        result.map(|()| len)
+    }
+
+    /// Trigger a factory scan and read `buf.len()` bytes of channel data
+    ///
+    /// Assumes the device is already in factory mode.
+    async fn factory_scan<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        buf: &mut [u8],
+    ) -> Result<(), Error<I2C::Error>> {
+        let mode = DeviceMode::Factory.to_register();
+        self.write_register(ADDR_DEVICE_MODE, mode | FACTORY_START_SCAN)
+            .await?;
+
+        let mut waited = 0;
+        while self.read_register(ADDR_DEVICE_MODE).await? & FACTORY_START_SCAN != 0 {
+            if waited >= FACTORY_SCAN_TIMEOUT_MS {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(FACTORY_SCAN_POLL_MS).await;
+            waited += FACTORY_SCAN_POLL_MS;
+        }
+
+        self.read_block(ADDR_FACTORY_RAW_DATA, buf).await
+    }
+
+    /// Recalibrate the panel baseline and store the result
+    ///
+    /// Useful after a cover glass change, assembly, or a large temperature
+    /// swing. Nothing may touch the panel while this runs. The register
+    /// sequence follows FocalTech's auto-calibration procedure for its
+    /// FT5x06/FT6x06 family, which is not part of the FT6336U datasheet:
+    ///
+    /// 1. Write `0x40` to `DEVICE_MODE` (`0x00`) to enter factory mode
+    /// 2. Write [`FACTORY_CMD_CALIBRATE`] (`0x04`) to [`ADDR_FACTORY_CMD`] (`0x02`)
+    /// 3. Poll `DEVICE_MODE` until bits 6:4 read 0, meaning calibration is done
+    /// 4. Enter factory mode again and write [`FACTORY_CMD_STORE_CALIBRATION`]
+    ///    (`0x05`) to [`ADDR_FACTORY_CMD`] to store the new baseline
+    /// 5. Write `0x00` to `DEVICE_MODE` to return to working mode
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used for mode switches and polling
+    ///
+    /// # Returns
+    /// `Error::Timeout` if calibration doesn't finish within
+    /// [`CALIBRATION_TIMEOUT_MS`]. The controller is then put back in working
+    /// mode on a best-effort basis, so touch reporting resumes without a
+    /// reset; the new baseline is not stored.
+    pub async fn recalibrate<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_device_mode(DeviceMode::Factory).await?;
+        delay.delay_ms(MODE_SWITCH_MS).await;
+        self.write_register(ADDR_FACTORY_CMD, FACTORY_CMD_CALIBRATE)
+            .await?;
+        delay.delay_ms(CALIBRATION_SETTLE_MS).await;
+
+        let mut waited = 0;
+        while self.read_device_mode().await? != DeviceMode::Working as u8 {
+            if waited >= CALIBRATION_TIMEOUT_MS {
+                // Don't leave touch reporting dead; the timeout is the error
+                // that matters, so a failed write here is not reported
+                let _ = self.write_device_mode(DeviceMode::Working).await;
+                delay.delay_ms(MODE_SWITCH_MS).await;
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(CALIBRATION_POLL_MS).await;
+            waited += CALIBRATION_POLL_MS;
+        }
+
+        self.write_device_mode(DeviceMode::Factory).await?;
+        delay.delay_ms(MODE_SWITCH_MS).await;
+        self.write_register(ADDR_FACTORY_CMD, FACTORY_CMD_STORE_CALIBRATION)
+            .await?;
+        delay.delay_ms(CALIBRATION_SETTLE_MS).await;
+        self.write_device_mode(DeviceMode::Working).await?;
+        delay.delay_ms(CALIBRATION_SETTLE_MS).await;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Gesture and Touch Status Methods
+    // =========================================================================
+
+    /// Read the gesture ID register
+    ///
+    /// # Returns
+    /// Gesture ID value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_gesture_id(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_GESTURE_ID).await
+    }
+
+    /// Read the current gesture, reporting each gesture only once
+    ///
+    /// The `GEST_ID` register is read-only, so it cannot be cleared after a
+    /// read and some firmwares keep the last gesture latched. Instead, the
+    /// driver remembers the last gesture it returned and reports
+    /// [`Gesture::None`] while the register still holds that same value.
+    /// Two identical gestures in a row are therefore only both reported if the
+    /// register returns to `None` in between.
+    ///
+    /// # Returns
+    /// The newly detected gesture, or `Gesture::None` if nothing new was seen.
+    /// Returns `Error::InvalidData` for unknown gesture codes.
+    #[cfg(feature = "gestures")]
+    pub async fn take_gesture(&mut self) -> Result<Gesture, Error<I2C::Error>> {
+        let gesture =
+            Gesture::from_register(self.read_gesture_id().await?).ok_or(Error::InvalidData)?;
+        if gesture == self.last_gesture {
+            return Ok(Gesture::None);
+        }
+        self.last_gesture = gesture;
+        Ok(gesture)
+    }
+
+    /// Check whether the controller's firmware implements gesture detection
+    ///
+    /// The controller has no capability register, so this probes for the
+    /// gesture tuning registers instead: `ID_G_RADIAN_VALUE` is read, written
+    /// with its lowest bit flipped, and read back, then the original value is
+    /// restored. Firmware built without gestures doesn't back these registers
+    /// and the probe value doesn't stick.
+    ///
+    /// Limitations:
+    /// - Writable tuning registers don't prove that the firmware actually
+    ///   reports gestures, only that it has the gesture configuration block.
+    /// - The radian value is briefly changed, so call this while the panel is
+    ///   idle, such as right after initialization.
+    /// - If the restoring write fails its error is returned and the register
+    ///   may be left holding the probe value.
+    ///
+    /// # Returns
+    /// `true` if the probe value was read back
+    #[cfg(feature = "gestures")]
+    pub async fn gestures_supported(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let original = self.read_register(ADDR_RADIAN_VALUE).await?;
+        let probe = original ^ 0x01;
+        self.write_register(ADDR_RADIAN_VALUE, probe).await?;
+        let readback = self.read_register(ADDR_RADIAN_VALUE).await;
+        self.write_register(ADDR_RADIAN_VALUE, original).await?;
+        Ok(readback? == probe)
+    }
+
+    /// Read the touch detection status register
+    ///
+    /// # Returns
+    /// Raw TD_STATUS register value
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_td_status(&mut self) -> Result<u8, Error<I2C::Error>> {
+        self.read_register(ADDR_TD_STATUS).await
+    }
+
+    /// Read the number of detected touch points
+    ///
+    /// # Returns
+    /// Number of touch points (0-2)
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_touch_number(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let val = self.read_register(ADDR_TD_STATUS).await?;
+        Ok(val & 0x0F)
+    }
+
+    /// Check whether anything is touching the panel
+    ///
+    /// A single-byte read of `TD_STATUS`, cheaper than a full [`Self::scan`].
+    /// The cached touch data is not updated.
+    ///
+    /// # Returns
+    /// `true` if at least one touch point is reported
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use core::convert::Infallible;
+    /// # struct MockI2c;
+    /// # impl embedded_hal::i2c::ErrorType for MockI2c {
+    /// #     type Error = Infallible;
+    /// # }
+    /// # impl I2c for MockI2c {
+    /// #     async fn write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn read(&mut self, _: u8, _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn transaction(&mut self, _: u8, _: &mut [embedded_hal_async::i2c::Operation<'_>]) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # async fn example() {
+    /// # let i2c = MockI2c;
+    /// use ft6336u_driver::FT6336U;
+    ///
+    /// let mut touch = FT6336U::new(i2c);
+    ///
+    /// if touch.has_touch().await.unwrap() {
+    ///     let data = touch.scan().await.unwrap();
+    ///     // Process touch points...
+    /// }
+    /// # }
+    /// ```
+    pub async fn has_touch(&mut self) -> Result<bool, Error<I2C::Error>> {
+        Ok(self.read_touch_number().await? > 0)
+    }
+
+    /// Read the status behind the controller's interrupt
+    ///
+    /// The FT6336U has no dedicated interrupt flag register: INT reflects the
+    /// touch detection status in `TD_STATUS` ([`ADDR_TD_STATUS`], `0x02`), so
+    /// this reads that register's touch count. A nonzero value after an INT
+    /// edge means the controller raised it; 0 means the edge came from
+    /// elsewhere, such as another device on a shared interrupt line.
+    ///
+    /// Reading does not clear anything. INT is released by the controller
+    /// itself, after its pulse in trigger mode or once the touch ends in
+    /// polling mode. Firmware that leaves the register unpopulated reads back
+    /// counts above 2 (typically `0xFF`), which are reported as 0.
+    ///
+    /// # Returns
+    /// The number of touches reported, 0 to 2
+    pub async fn read_interrupt_status(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let count = self.read_touch_number().await?;
+        Ok(if count as usize > MAX_TOUCH_POINTS {
+            0
+        } else {
+            count
+        })
+    }
+
+    /// Wait until every finger has lifted
+    ///
+    /// Polls [`Self::has_touch`] every `poll_ms` milliseconds until no touch is
+    /// reported, then marks the cached points as released so the next scan
+    /// reports any new contact as a fresh `Touch`.
     ///
     /// # Arguments
-    /// * `mode` - The desired device mode
-    pub async fn write_device_mode(&mut self, mode: DeviceMode) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DEVICE_MODE, mode.to_register()).await
-    }
-
-    // =========================================================================
-    // Gesture and Touch Status Methods
-    // =========================================================================
-
-    /// Read the gesture ID register
+    /// * `delay` - Delay provider used between polls
+    /// * `poll_ms` - Interval between polls, in milliseconds
+    /// * `timeout_ms` - Maximum time to wait, in milliseconds
     ///
     /// # Returns
-    /// Gesture ID value
-    pub async fn read_gesture_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_GESTURE_ID).await
+    /// `Error::Timeout` if a touch is still reported when the timeout elapses,
+    /// in which case the cached points are left as they are
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use core::convert::Infallible;
+    /// # struct MockI2c;
+    /// # impl embedded_hal::i2c::ErrorType for MockI2c {
+    /// #     type Error = Infallible;
+    /// # }
+    /// # impl I2c for MockI2c {
+    /// #     async fn write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn read(&mut self, _: u8, _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// #     async fn transaction(&mut self, _: u8, _: &mut [embedded_hal_async::i2c::Operation<'_>]) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # struct MockDelay;
+    /// # impl embedded_hal_async::delay::DelayNs for MockDelay {
+    /// #     async fn delay_ns(&mut self, _: u32) {}
+    /// # }
+    /// # async fn example() {
+    /// # let (i2c, mut delay) = (MockI2c, MockDelay);
+    /// use ft6336u_driver::FT6336U;
+    ///
+    /// let mut touch = FT6336U::new(i2c);
+    ///
+    /// // Wait for the finger to lift, checking every 20ms
+    /// touch.wait_for_release(&mut delay, 20, 1000).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn wait_for_release<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        poll_ms: u32,
+        timeout_ms: u32,
+    ) -> Result<(), Error<I2C::Error>> {
+        let mut waited = 0;
+        while self.has_touch().await? {
+            if waited >= timeout_ms {
+                return Err(Error::Timeout);
+            }
+            delay.delay_ms(poll_ms).await;
+            waited = waited.saturating_add(poll_ms);
+        }
+        self.state.release_all();
+        Ok(())
     }
 
-    /// Read the touch detection status register
+    /// Wait for the INT pin to signal a touch, then scan
+    ///
+    /// The FT6336U pulls INT low while it has a report to read. This awaits
+    /// `int` going low, then behaves like [`Self::scan`]. The frame is
+    /// attributed to [`ScanSource::Interrupt`] by [`Self::last_scan_source`].
+    ///
+    /// # Arguments
+    /// * `int` - The controller's active-low interrupt pin
     ///
     /// # Returns
-    /// Raw TD_STATUS register value
-    pub async fn read_td_status(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TD_STATUS).await
+    /// The scanned touch data, or `Error::Pin` if waiting on the pin fails
+    pub async fn wait_for_touch<P: Wait>(
+        &mut self,
+        int: &mut P,
+    ) -> Result<TouchData, Error<I2C::Error>> {
+        int.wait_for_low().await.map_err(|_| Error::Pin)?;
+        let data = self.scan().await?;
+        self.last_source = ScanSource::Interrupt;
+        Ok(data)
     }
 
-    /// Read the number of detected touch points
+    /// Stream touch frames, scanning once per interrupt
     ///
-    /// # Returns
-    /// Number of touch points (0-2)
-    pub async fn read_touch_number(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TD_STATUS).await?;
-        Ok(val & 0x0F)
+    /// Returns a [`TouchFrames`] whose `next()` awaits `int` and yields the
+    /// scanned frame, for loops like:
+    ///
+    /// ```rust,ignore
+    /// let mut frames = touch.frames(&mut int);
+    /// while let Some(frame) = frames.next().await {
+    ///     let frame = frame?;
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// See [`TouchFrames`] for cancellation safety.
+    ///
+    /// # Arguments
+    /// * `int` - The controller's active-low interrupt pin
+    pub fn frames<'a, P: Wait>(&'a mut self, int: &'a mut P) -> TouchFrames<'a, I2C, P, HISTORY> {
+        TouchFrames::new(self, int)
     }
 
     // =========================================================================
@@ -179,32 +1212,31 @@ where
     ///
     /// # Returns
     /// X coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch1_x(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c
-            .write_read(I2C_ADDR, &[ADDR_TOUCH1_X], &mut buf)
-            .await?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH1_X, &mut buf).await?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read Y coordinate of touch point 1
     ///
     /// # Returns
     /// Y coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch1_y(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c
-            .write_read(I2C_ADDR, &[ADDR_TOUCH1_Y], &mut buf)
-            .await?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH1_Y, &mut buf).await?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read event type of touch point 1
     ///
     /// # Returns
     /// Event type (0=down, 1=up, 2=contact)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch1_event(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH1_EVENT).await?;
+        let val = self.read_register(ADDR_TOUCH1_EVENT).await?;
         Ok(val >> 6)
     }
 
@@ -212,8 +1244,9 @@ where
     ///
     /// # Returns
     /// Touch point ID (0 or 1)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch1_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH1_ID).await?;
+        let val = self.read_register(ADDR_TOUCH1_ID).await?;
         Ok(val >> 4)
     }
 
@@ -221,16 +1254,18 @@ where
     ///
     /// # Returns
     /// Touch weight value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch1_weight(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TOUCH1_WEIGHT).await
+        self.read_register(ADDR_TOUCH1_WEIGHT).await
     }
 
     /// Read miscellaneous data for touch point 1
     ///
     /// # Returns
     /// Misc data value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch1_misc(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH1_MISC).await?;
+        let val = self.read_register(ADDR_TOUCH1_MISC).await?;
         Ok(val >> 4)
     }
 
@@ -242,32 +1277,31 @@ where
     ///
     /// # Returns
     /// X coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch2_x(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c
-            .write_read(I2C_ADDR, &[ADDR_TOUCH2_X], &mut buf)
-            .await?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH2_X, &mut buf).await?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read Y coordinate of touch point 2
     ///
     /// # Returns
     /// Y coordinate (0-4095, 12-bit value)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch2_y(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c
-            .write_read(I2C_ADDR, &[ADDR_TOUCH2_Y], &mut buf)
-            .await?;
-        Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
+        self.read_block(ADDR_TOUCH2_Y, &mut buf).await?;
+        Ok(parse_coord(&buf, self.byte_order))
     }
 
     /// Read event type of touch point 2
     ///
     /// # Returns
     /// Event type (0=down, 1=up, 2=contact)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch2_event(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH2_EVENT).await?;
+        let val = self.read_register(ADDR_TOUCH2_EVENT).await?;
         Ok(val >> 6)
     }
 
@@ -275,8 +1309,9 @@ where
     ///
     /// # Returns
     /// Touch point ID (0 or 1)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch2_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH2_ID).await?;
+        let val = self.read_register(ADDR_TOUCH2_ID).await?;
         Ok(val >> 4)
     }
 
@@ -284,16 +1319,18 @@ where
     ///
     /// # Returns
     /// Touch weight value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch2_weight(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TOUCH2_WEIGHT).await
+        self.read_register(ADDR_TOUCH2_WEIGHT).await
     }
 
     /// Read miscellaneous data for touch point 2
     ///
     /// # Returns
     /// Misc data value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch2_misc(&mut self) -> Result<u8, Error<I2C::Error>> {
-        let val = self.read_byte(ADDR_TOUCH2_MISC).await?;
+        let val = self.read_register(ADDR_TOUCH2_MISC).await?;
         Ok(val >> 4)
     }
 
@@ -305,24 +1342,97 @@ where
     ///
     /// # Returns
     /// Threshold value (lower = more sensitive)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_touch_threshold(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_THRESHOLD).await
+        self.read_register(ADDR_THRESHOLD).await
+    }
+
+    /// Write the touch detection threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Threshold value (lower = more sensitive)
+    pub async fn write_touch_threshold(&mut self, threshold: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_THRESHOLD, threshold).await
+    }
+
+    /// Raise or lower the touch detection threshold from its current value
+    ///
+    /// Reads the threshold, adds `delta`, and writes the result back, so
+    /// sensitivity can be tuned in steps without knowing the panel's absolute
+    /// value. A positive `delta` makes the panel less sensitive. The result
+    /// saturates at the ends of the register's `0..=255` range instead of
+    /// wrapping.
+    ///
+    /// # Arguments
+    /// * `delta` - Amount to add to the current threshold
+    ///
+    /// # Returns
+    /// The threshold that was written
+    pub async fn adjust_threshold(&mut self, delta: i8) -> Result<u8, Error<I2C::Error>> {
+        let threshold = self
+            .read_touch_threshold()
+            .await?
+            .saturating_add_signed(delta);
+        self.write_touch_threshold(threshold).await?;
+        Ok(threshold)
     }
 
     /// Read the filter coefficient
     ///
     /// # Returns
     /// Filter coefficient value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_filter_coefficient(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_FILTER_COE).await
+        self.read_register(ADDR_FILTER_COE).await
+    }
+
+    /// Write the filter coefficient
+    ///
+    /// # Arguments
+    /// * `coefficient` - Raw filter coefficient value
+    pub async fn write_filter_coefficient(
+        &mut self,
+        coefficient: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_FILTER_COE, coefficient).await
+    }
+
+    /// Read the filter coefficient as the nearest [`FilterStrength`]
+    ///
+    /// Any register value is accepted, so vendor-tuned coefficients are
+    /// classified rather than rejected.
+    pub async fn filter_strength(&mut self) -> Result<FilterStrength, Error<I2C::Error>> {
+        let val = self.read_filter_coefficient().await?;
+        Ok(FilterStrength::from_register(val))
+    }
+
+    /// Set the filter coefficient from a [`FilterStrength`]
+    ///
+    /// # Arguments
+    /// * `strength` - Desired filter strength
+    pub async fn set_filter_strength(
+        &mut self,
+        strength: FilterStrength,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_filter_coefficient(strength.to_register()).await
     }
 
     /// Read the control mode register
     ///
     /// # Returns
     /// Control mode value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_ctrl_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_CTRL).await
+        self.read_register(ADDR_CTRL).await
+    }
+
+    /// Read the control mode as a typed value
+    ///
+    /// # Returns
+    /// The control mode, or `Error::InvalidData` if the register holds an unknown value
+    pub async fn ctrl_mode(&mut self) -> Result<CtrlMode, Error<I2C::Error>> {
+        let val = self.read_ctrl_mode().await?;
+        CtrlMode::from_register(val).ok_or(Error::InvalidData)
     }
 
     /// Write the control mode
@@ -330,31 +1440,140 @@ where
     /// # Arguments
     /// * `mode` - Control mode (KeepActive or SwitchToMonitor)
     pub async fn write_ctrl_mode(&mut self, mode: CtrlMode) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_CTRL, mode as u8).await
+        self.write_register(ADDR_CTRL, mode as u8).await?;
+        self.ctrl_mode = mode;
+        Ok(())
     }
 
     /// Read the time period to enter monitor mode
     ///
     /// # Returns
     /// Time period value in seconds
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_time_period_enter_monitor(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_TIME_ENTER_MONITOR).await
+        self.read_register(ADDR_TIME_ENTER_MONITOR).await
+    }
+
+    /// Write the time period to enter monitor mode
+    ///
+    /// # Arguments
+    /// * `seconds` - Idle time in seconds before entering monitor mode
+    pub async fn write_time_period_enter_monitor(
+        &mut self,
+        seconds: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_TIME_ENTER_MONITOR, seconds).await
     }
 
     /// Read the active mode report rate
     ///
     /// # Returns
     /// Report rate in Hz
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_active_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_ACTIVE_MODE_RATE).await
+        self.read_register(ADDR_ACTIVE_MODE_RATE).await
+    }
+
+    /// Write the active mode report rate
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
+    pub async fn write_active_rate(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_ACTIVE_MODE_RATE, rate).await
     }
 
     /// Read the monitor mode report rate
     ///
     /// # Returns
     /// Report rate in Hz
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_monitor_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_MONITOR_MODE_RATE).await
+        self.read_register(ADDR_MONITOR_MODE_RATE).await
+    }
+
+    /// Write the monitor mode report rate
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
+    pub async fn write_monitor_rate(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_MONITOR_MODE_RATE, rate).await
+    }
+
+    /// Read the active mode report rate, validated
+    ///
+    /// # Returns
+    /// Report rate in Hz, or `Error::InvalidData` if it is outside
+    /// [`MIN_REPORT_RATE_HZ`]..=[`MAX_REPORT_RATE_HZ`]
+    pub async fn active_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let rate = self.read_active_rate().await?;
+        Self::check_report_rate(rate)
+    }
+
+    /// Write the active mode report rate after validating it
+    ///
+    /// Unlike [`Self::write_active_rate`], rates outside
+    /// [`MIN_REPORT_RATE_HZ`]..=[`MAX_REPORT_RATE_HZ`] are rejected before
+    /// anything is written. A rate of 0 in particular stops touch reporting.
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if `rate` is out of range
+    pub async fn write_active_rate_checked(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        let rate = Self::check_report_rate(rate)?;
+        self.write_active_rate(rate).await
+    }
+
+    /// Read the monitor mode report rate, validated
+    ///
+    /// # Returns
+    /// Report rate in Hz, or `Error::InvalidData` if it is outside
+    /// [`MIN_REPORT_RATE_HZ`]..=[`MAX_REPORT_RATE_HZ`]
+    pub async fn monitor_rate(&mut self) -> Result<u8, Error<I2C::Error>> {
+        let rate = self.read_monitor_rate().await?;
+        Self::check_report_rate(rate)
+    }
+
+    /// Write the monitor mode report rate after validating it
+    ///
+    /// Like [`Self::write_active_rate_checked`], for the monitor mode rate.
+    ///
+    /// # Arguments
+    /// * `rate` - Report rate in Hz
+    ///
+    /// # Returns
+    /// `Error::InvalidData` if `rate` is out of range
+    pub async fn write_monitor_rate_checked(&mut self, rate: u8) -> Result<(), Error<I2C::Error>> {
+        let rate = Self::check_report_rate(rate)?;
+        self.write_monitor_rate(rate).await
+    }
+
+    /// Validate a report rate against the accepted range
+    fn check_report_rate(rate: u8) -> Result<u8, Error<I2C::Error>> {
+        if (MIN_REPORT_RATE_HZ..=MAX_REPORT_RATE_HZ).contains(&rate) {
+            Ok(rate)
+        } else {
+            Err(Error::InvalidData)
+        }
+    }
+
+    /// Apply a set of operating parameters
+    ///
+    /// Writes every field of `config` to the device, one register at a time.
+    /// If a write fails the error is returned immediately, leaving the
+    /// remaining registers unchanged.
+    ///
+    /// # Arguments
+    /// * `config` - The configuration to apply, e.g. [`Config::recommended`]
+    pub async fn configure(&mut self, config: &Config) -> Result<(), Error<I2C::Error>> {
+        self.write_g_mode(config.gesture_mode).await?;
+        self.write_ctrl_mode(config.ctrl_mode).await?;
+        self.write_touch_threshold(config.threshold).await?;
+        self.write_active_rate(config.active_rate).await?;
+        self.write_monitor_rate(config.monitor_rate).await?;
+        self.write_time_period_enter_monitor(config.time_enter_monitor)
+            .await
     }
 
     // =========================================================================
@@ -365,96 +1584,181 @@ where
     ///
     /// # Returns
     /// Radian value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_radian_value(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_RADIAN_VALUE).await
+        self.read_register(ADDR_RADIAN_VALUE).await
     }
 
     /// Write the radian value for gesture detection
     ///
     /// # Arguments
     /// * `val` - Radian value to set
+    #[cfg(feature = "gestures")]
     pub async fn write_radian_value(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_RADIAN_VALUE, val).await
+        self.write_register(ADDR_RADIAN_VALUE, val).await
     }
 
     /// Read the offset for left/right gesture detection
     ///
     /// # Returns
     /// Offset value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_offset_left_right(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_OFFSET_LEFT_RIGHT).await
+        self.read_register(ADDR_OFFSET_LEFT_RIGHT).await
     }
 
     /// Write the offset for left/right gesture detection
     ///
     /// # Arguments
     /// * `val` - Offset value to set
+    #[cfg(feature = "gestures")]
     pub async fn write_offset_left_right(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_OFFSET_LEFT_RIGHT, val).await
+        self.write_register(ADDR_OFFSET_LEFT_RIGHT, val).await
     }
 
     /// Read the offset for up/down gesture detection
     ///
     /// # Returns
     /// Offset value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_offset_up_down(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_OFFSET_UP_DOWN).await
+        self.read_register(ADDR_OFFSET_UP_DOWN).await
     }
 
     /// Write the offset for up/down gesture detection
     ///
     /// # Arguments
     /// * `val` - Offset value to set
+    #[cfg(feature = "gestures")]
     pub async fn write_offset_up_down(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_OFFSET_UP_DOWN, val).await
+        self.write_register(ADDR_OFFSET_UP_DOWN, val).await
     }
 
     /// Read the distance for left/right gesture detection
     ///
     /// # Returns
     /// Distance value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_distance_left_right(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_DISTANCE_LEFT_RIGHT).await
+        self.read_register(ADDR_DISTANCE_LEFT_RIGHT).await
     }
 
     /// Write the distance for left/right gesture detection
     ///
     /// # Arguments
     /// * `val` - Distance value to set
+    #[cfg(feature = "gestures")]
     pub async fn write_distance_left_right(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DISTANCE_LEFT_RIGHT, val).await
+        self.write_register(ADDR_DISTANCE_LEFT_RIGHT, val).await
     }
 
     /// Read the distance for up/down gesture detection
     ///
     /// # Returns
     /// Distance value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_distance_up_down(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_DISTANCE_UP_DOWN).await
+        self.read_register(ADDR_DISTANCE_UP_DOWN).await
     }
 
     /// Write the distance for up/down gesture detection
     ///
     /// # Arguments
     /// * `val` - Distance value to set
+    #[cfg(feature = "gestures")]
     pub async fn write_distance_up_down(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DISTANCE_UP_DOWN, val).await
+        self.write_register(ADDR_DISTANCE_UP_DOWN, val).await
     }
 
     /// Read the distance for zoom gesture detection
     ///
     /// # Returns
     /// Distance value
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_distance_zoom(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_DISTANCE_ZOOM).await
+        self.read_register(ADDR_DISTANCE_ZOOM).await
     }
 
     /// Write the distance for zoom gesture detection
     ///
     /// # Arguments
     /// * `val` - Distance value to set
+    #[cfg(feature = "gestures")]
     pub async fn write_distance_zoom(&mut self, val: u8) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_DISTANCE_ZOOM, val).await
+        self.write_register(ADDR_DISTANCE_ZOOM, val).await
+    }
+
+    /// Read all gesture tuning registers
+    ///
+    /// Uses one or six transactions depending on [`Self::set_auto_increment`].
+    #[cfg(feature = "gestures")]
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_gesture_params(&mut self) -> Result<GestureParams, Error<I2C::Error>> {
+        let mut buf = [0u8; 6];
+        if self.auto_increment {
+            self.read_block(ADDR_RADIAN_VALUE, &mut buf).await?;
+        } else {
+            for (addr, byte) in (ADDR_RADIAN_VALUE..).zip(buf.iter_mut()) {
+                *byte = self.read_register(addr).await?;
+            }
+        }
+        Ok(GestureParams {
+            radian_value: buf[0],
+            offset_left_right: buf[1],
+            offset_up_down: buf[2],
+            distance_left_right: buf[3],
+            distance_up_down: buf[4],
+            distance_zoom: buf[5],
+        })
+    }
+
+    /// Write all gesture tuning registers
+    ///
+    /// If a write fails the error is returned immediately, leaving the
+    /// remaining registers unchanged.
+    ///
+    /// # Arguments
+    /// * `params` - Values to write, e.g. from [`Self::read_gesture_params`]
+    #[cfg(feature = "gestures")]
+    pub async fn apply_gesture_params(
+        &mut self,
+        params: &GestureParams,
+    ) -> Result<(), Error<I2C::Error>> {
+        self.write_radian_value(params.radian_value).await?;
+        self.write_offset_left_right(params.offset_left_right)
+            .await?;
+        self.write_offset_up_down(params.offset_up_down).await?;
+        self.write_distance_left_right(params.distance_left_right)
+            .await?;
+        self.write_distance_up_down(params.distance_up_down).await?;
+        self.write_distance_zoom(params.distance_zoom).await
+    }
+
+    /// Restore the mode and gesture parameters to their power-on defaults
+    ///
+    /// Writes the `DEFAULT_*` constants to the threshold, filter, control,
+    /// monitor timing, report rate and gesture parameter registers, undoing
+    /// runtime tuning without a hardware reset. The interrupt mode is left
+    /// as is. If a write fails the error is returned immediately, leaving the
+    /// remaining registers unchanged.
+    pub async fn restore_defaults(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.write_touch_threshold(DEFAULT_THRESHOLD).await?;
+        self.write_register(ADDR_FILTER_COE, DEFAULT_FILTER_COE)
+            .await?;
+        self.write_ctrl_mode(CtrlMode::SwitchToMonitor).await?;
+        self.write_time_period_enter_monitor(DEFAULT_TIME_ENTER_MONITOR)
+            .await?;
+        self.write_active_rate(DEFAULT_ACTIVE_RATE).await?;
+        self.write_monitor_rate(DEFAULT_MONITOR_RATE).await?;
+        #[cfg(feature = "gestures")]
+        self.apply_gesture_params(&GestureParams::default()).await?;
+        Ok(())
     }
 
     // =========================================================================
@@ -463,13 +1767,19 @@ where
 
     /// Read the library version from the device
     ///
+    /// Uses one or two transactions depending on [`Self::set_auto_increment`].
+    ///
     /// # Returns
     /// 16-bit library version number
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_library_version(&mut self) -> Result<u16, Error<I2C::Error>> {
         let mut buf = [0u8; 2];
-        self.i2c
-            .write_read(I2C_ADDR, &[ADDR_LIBRARY_VERSION_H], &mut buf)
-            .await?;
+        if self.auto_increment {
+            self.read_block(ADDR_LIBRARY_VERSION_H, &mut buf).await?;
+        } else {
+            buf[0] = self.read_register(ADDR_LIBRARY_VERSION_H).await?;
+            buf[1] = self.read_register(ADDR_LIBRARY_VERSION_L).await?;
+        }
         Ok((((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16))
     }
 
@@ -477,16 +1787,33 @@ where
     ///
     /// # Returns
     /// Chip ID (should be 0x64 for FT6336U)
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_chip_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_CHIP_ID).await
+        self.read_register(ADDR_CHIP_ID).await
+    }
+
+    /// Check that the controller still responds with the expected chip ID
+    ///
+    /// Meant to be called periodically in long-running devices. A controller
+    /// that has hung or browned out often still ACKs but reads back `0x00` or
+    /// garbage, which is reported as `Ok(false)`; a controller that stops
+    /// ACKing altogether is reported as a bus error. Either way, a hardware
+    /// reset is the usual recovery.
+    ///
+    /// # Returns
+    /// `true` if the chip ID is [`CHIP_ID`], `false` if it is anything else
+    #[must_use = "the health check result should be acted on"]
+    pub async fn health_check(&mut self) -> Result<bool, Error<I2C::Error>> {
+        Ok(self.read_chip_id().await? == CHIP_ID)
     }
 
     /// Read the gesture/interrupt mode
     ///
     /// # Returns
     /// G_MODE register value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_g_mode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_G_MODE).await
+        self.read_register(ADDR_G_MODE).await
     }
 
     /// Write the gesture/interrupt mode
@@ -494,114 +1821,408 @@ where
     /// # Arguments
     /// * `mode` - Gesture mode (Polling or Trigger)
     pub async fn write_g_mode(&mut self, mode: GestureMode) -> Result<(), Error<I2C::Error>> {
-        self.write_byte(ADDR_G_MODE, mode as u8).await
+        self.write_register(ADDR_G_MODE, mode as u8).await?;
+        self.gesture_mode = mode;
+        Ok(())
     }
 
     /// Read the power mode
     ///
     /// # Returns
     /// Power mode value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_pwrmode(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_POWER_MODE).await
+        self.read_register(ADDR_POWER_MODE).await
+    }
+
+    /// Put the controller into hibernation
+    ///
+    /// The controller stops scanning and stops answering on the bus until it
+    /// is woken by a reset through its reset pin, so this should be the last
+    /// transfer before powering down.
+    pub async fn hibernate(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.write_register(ADDR_POWER_MODE, POWER_MODE_HIBERNATE)
+            .await
     }
 
     /// Read the firmware ID
     ///
     /// # Returns
     /// Firmware ID value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_firmware_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_FIRMWARE_ID).await
+        self.read_register(ADDR_FIRMWARE_ID).await
     }
 
     /// Read the Focaltech ID
     ///
     /// # Returns
     /// Focaltech ID value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_focaltech_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_FOCALTECH_ID).await
+        self.read_register(ADDR_FOCALTECH_ID).await
     }
 
     /// Read the release code ID
     ///
     /// # Returns
     /// Release code ID value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_release_code_id(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_RELEASE_CODE_ID).await
+        self.read_register(ADDR_RELEASE_CODE_ID).await
     }
 
     /// Read the device state
     ///
     /// # Returns
     /// Device state value
+    #[must_use = "reading a register has no effect besides returning its value"]
     pub async fn read_state(&mut self) -> Result<u8, Error<I2C::Error>> {
-        self.read_byte(ADDR_STATE).await
+        self.read_register(ADDR_STATE).await
+    }
+
+    /// Read the library version, chip ID, interrupt mode, power mode and
+    /// firmware ID together
+    ///
+    /// The registers from `LIB_VER_H` through `FIRMID` are contiguous, so this
+    /// is a single transaction when auto-increment is enabled (see
+    /// [`Self::set_auto_increment`]), and one read per register otherwise.
+    ///
+    /// # Returns
+    /// The parsed registers, or `Error::InvalidData` if `G_MODE` holds an
+    /// unknown value
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn device_info(&mut self) -> Result<DeviceInfo, Error<I2C::Error>> {
+        let mut buf = [0u8; SYSTEM_INFO_LEN];
+        if self.auto_increment {
+            self.read_block(ADDR_LIBRARY_VERSION_H, &mut buf).await?;
+        } else {
+            for (addr, byte) in (ADDR_LIBRARY_VERSION_H..).zip(buf.iter_mut()) {
+                *byte = self.read_register(addr).await?;
+            }
+        }
+        parse_device_info(&buf).ok_or(Error::InvalidData)
+    }
+
+    // =========================================================================
+    // Extended Mode Methods
+    // =========================================================================
+
+    /// Select the register used for glove mode
+    ///
+    /// Glove mode is a FocalTech extension rather than part of the FT6336U
+    /// datasheet. Most firmware builds that support it use
+    /// [`ADDR_GLOVE_MODE`] (`0xC0`), the default; use this if your panel
+    /// vendor documents a different address.
+    ///
+    /// # Arguments
+    /// * `addr` - Glove mode enable register address
+    pub fn set_glove_mode_addr(&mut self, addr: u8) {
+        self.glove_mode_addr = addr;
+    }
+
+    /// Read whether glove (high sensitivity) mode is enabled
+    ///
+    /// Firmware without glove mode may read back any value from this address;
+    /// check with your panel vendor before relying on it.
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_glove_mode(&mut self) -> Result<bool, Error<I2C::Error>> {
+        Ok(self.read_register(self.glove_mode_addr).await? != 0)
+    }
+
+    /// Enable or disable glove (high sensitivity) mode
+    ///
+    /// Raises sensitivity so gloved fingers register, at the cost of noise
+    /// immunity. Firmware without glove mode ignores the write or treats the
+    /// address as something else, so only enable it on panels known to
+    /// support it.
+    ///
+    /// # Arguments
+    /// * `enabled` - `true` to enable glove mode
+    pub async fn write_glove_mode(&mut self, enabled: bool) -> Result<(), Error<I2C::Error>> {
+        self.write_register(self.glove_mode_addr, enabled as u8)
+            .await
+    }
+
+    /// Read the panel resolution configured in the firmware, if it has one
+    ///
+    /// Some panel vendors' firmware stores the panel size as big-endian 16-bit
+    /// values starting at [`ADDR_PANEL_RESOLUTION`] (`0x98`/`0x99` for the
+    /// width, `0x9A`/`0x9B` for the height). These registers are not part of
+    /// the FT6336U datasheet, and firmware without them usually reads back
+    /// zeros there. The result can be passed to [`Self::set_scaling`] as the
+    /// source range instead of hard-coding it.
+    ///
+    /// # Returns
+    /// `(width, height)` in touch coordinates, or `None` if the registers read
+    /// as zero or as a size beyond the 12-bit coordinate range
+    #[must_use = "reading a register has no effect besides returning its value"]
+    pub async fn read_panel_resolution(&mut self) -> Result<Option<(u16, u16)>, Error<I2C::Error>> {
+        let mut buf = [0u8; RESOLUTION_LEN];
+        if self.auto_increment {
+            self.read_block(ADDR_PANEL_RESOLUTION, &mut buf).await?;
+        } else {
+            for (addr, byte) in (ADDR_PANEL_RESOLUTION..).zip(buf.iter_mut()) {
+                *byte = self.read_register(addr).await?;
+            }
+        }
+        Ok(parse_resolution(&buf))
     }
 
     // =========================================================================
     // High-Level Scan Method
     // =========================================================================
 
+    /// Read the positions of the currently active touch points
+    ///
+    /// A lighter alternative to [`Self::scan`] for callers that only need to
+    /// know where fingers are right now. Points are returned in report order
+    /// regardless of their hardware ID, with active points first. Points
+    /// lighter than the [`Self::set_min_weight`] threshold are skipped, and
+    /// [`Self::set_scaling`] and [`Self::set_edge_margin`] apply just as they
+    /// do to [`Self::scan`], so both return the same coordinate space. No
+    /// status tracking is done and the driver's cached touch data is left
+    /// untouched.
+    ///
+    /// # Returns
+    /// Up to two `(x, y)` positions; unused slots are `None`
+    pub async fn positions(&mut self) -> Result<Positions, Error<I2C::Error>> {
+        let mut block = self.read_touch_block().await?;
+        self.state.filter_weight(&mut block);
+        self.state.map_to_display(&mut block);
+        let mut positions = [None; MAX_TOUCH_POINTS];
+        for (slot, point) in positions
+            .iter_mut()
+            .zip(block.points.iter())
+            .take(block.count as usize)
+        {
+            *slot = Some((point.x as Coord, point.y as Coord));
+        }
+        Ok(positions)
+    }
+
     /// Scan for touch events and update internal touch data
     ///
     /// This is the main method to call periodically or in response to interrupts
     /// to read the current touch state. It reads all touch point data and updates
     /// the internal touch data structure.
     ///
+    /// `TD_STATUS` and touch point 1 are read in a single burst. Touch point 2's
+    /// registers are only read when two touches are reported, so a single-touch
+    /// scan costs one bus transaction.
+    ///
     /// # Returns
     /// TouchData containing the number of touch points and their coordinates/status
+    #[must_use = "the scanned touch data is returned, not just cached"]
     pub async fn scan(&mut self) -> Result<TouchData, Error<I2C::Error>> {
-        // Read the number of touch points
-        let touch_count = self.read_touch_number().await?;
-        self.touch_data.touch_count = touch_count;
-
-        if touch_count == 0 {
-            // No touches - mark both points as released
-            self.touch_data.points[0].status = TouchStatus::Release;
-            self.touch_data.points[1].status = TouchStatus::Release;
-        } else if touch_count == 1 {
-            // Single touch point
-            let id1 = self.read_touch1_id().await? as usize;
-            if id1 < 2 {
-                // Update status: if previously released, mark as new touch, otherwise streaming
-                let prev_status = self.touch_data.points[id1].status;
-                self.touch_data.points[id1].status = match prev_status {
-                    TouchStatus::Release => TouchStatus::Touch,
-                    _ => TouchStatus::Stream,
-                };
-
-                // Read coordinates
-                self.touch_data.points[id1].x = self.read_touch1_x().await?;
-                self.touch_data.points[id1].y = self.read_touch1_y().await?;
-
-                // Mark the other point as released
-                let other_id = (!id1) & 0x01;
-                self.touch_data.points[other_id].status = TouchStatus::Release;
-            }
-        } else {
-            // Two touch points
-            let id1 = self.read_touch1_id().await? as usize;
-            if id1 < 2 {
-                let prev_status1 = self.touch_data.points[id1].status;
-                self.touch_data.points[id1].status = match prev_status1 {
-                    TouchStatus::Release => TouchStatus::Touch,
-                    _ => TouchStatus::Stream,
-                };
-                self.touch_data.points[id1].x = self.read_touch1_x().await?;
-                self.touch_data.points[id1].y = self.read_touch1_y().await?;
-            }
+        let data = self.scan_untracked().await?;
+        Ok(self.record(data))
+    }
 
-            let id2 = self.read_touch2_id().await? as usize;
-            if id2 < 2 {
-                let prev_status2 = self.touch_data.points[id2].status;
-                self.touch_data.points[id2].status = match prev_status2 {
-                    TouchStatus::Release => TouchStatus::Touch,
-                    _ => TouchStatus::Stream,
-                };
-                self.touch_data.points[id2].x = self.read_touch2_x().await?;
-                self.touch_data.points[id2].y = self.read_touch2_y().await?;
-            }
+    /// Read and process the touch registers without updating the history
+    async fn scan_untracked(&mut self) -> Result<TouchData, Error<I2C::Error>> {
+        let mut confirmation =
+            Confirmation::new(self.read_touch_block().await?, self.state.tracking);
+        for _ in 1..self.state.confirm_samples {
+            confirmation.add(&self.read_touch_block().await?);
         }
+        Ok(self.state.process(confirmation.finish()))
+    }
+
+    /// Number a scanned frame, append it to the history and hand it back
+    fn record(&mut self, mut data: TouchData) -> TouchData {
+        self.seq = self.seq.wrapping_add(1);
+        data.seq = self.seq;
+        self.last_source = ScanSource::Poll;
+        self.history.push(data);
+        data
+    }
+
+    /// Scan for touch events and track how long each point has been pressed
+    ///
+    /// Behaves like [`Self::scan`], and additionally fills in each point's
+    /// `pressed_ms` from the timestamp of its latest `Touch`. Use it
+    /// consistently in place of `scan()`, since presses that start during an
+    /// untimed scan have no recorded start time.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds from any monotonic source (may wrap)
+    #[must_use = "the scanned touch data is returned, not just cached"]
+    pub async fn scan_at(&mut self, now_ms: u32) -> Result<TouchData, Error<I2C::Error>> {
+        self.scan_untracked().await?;
+        let data = self.state.track_pressed_time(now_ms);
+        Ok(self.record(data))
+    }
+
+    /// Scan for touch events and measure how long the scan took
+    ///
+    /// Behaves like [`Self::scan`], calling `now` immediately before and after
+    /// it. The crate has no time source of its own, so `now` can read any
+    /// monotonic counter, such as a microsecond timer or a cycle counter; the
+    /// elapsed time is in the same units and handles the counter wrapping.
+    /// Useful for sizing a polling loop to the actual bus timing.
+    ///
+    /// # Arguments
+    /// * `now` - Returns the current value of a monotonic counter
+    ///
+    /// # Returns
+    /// The touch data and the counter difference across the scan
+    #[must_use = "the scanned touch data is returned, not just cached"]
+    pub async fn scan_timed(
+        &mut self,
+        mut now: impl FnMut() -> u32,
+    ) -> Result<(TouchData, u32), Error<I2C::Error>> {
+        let start = now();
+        let data = self.scan().await?;
+        Ok((data, now().wrapping_sub(start)))
+    }
+
+    /// Scan for touch events and read the gesture register in one transaction
+    ///
+    /// Reads from `GEST_ID` through touch point 2's registers in a single
+    /// burst, so the gesture and the touch data are captured together. This
+    /// avoids the race where a new gesture arrives between a separate scan and
+    /// gesture read in trigger mode. The touch data is processed exactly as in
+    /// [`Self::scan`], and with [`Self::set_max_points`] set to 1 the burst
+    /// stops before touch point 2's registers.
+    ///
+    /// # Returns
+    /// The touch data and the gesture register at the time of the read.
+    /// Firmware that doesn't populate the gesture register, or reports an
+    /// unknown gesture code, yields `Gesture::None` so the touch data is never lost.
+    #[cfg(feature = "gestures")]
+    #[must_use = "the scanned touch data is returned, not just cached"]
+    pub async fn scan_with_gesture(&mut self) -> Result<(TouchData, Gesture), Error<I2C::Error>> {
+        // With a single point, stop before touch point 2's registers
+        let len = if self.max_points == 1 {
+            1 + TOUCH1_BLOCK_LEN
+        } else {
+            GESTURE_BLOCK_LEN
+        };
+        let mut buf = [0u8; GESTURE_BLOCK_LEN];
+        self.read_block(ADDR_GESTURE_ID, &mut buf[..len]).await?;
+
+        let gesture = Gesture::from_register(buf[0]).unwrap_or(Gesture::None);
+        let mut block = TouchBlock::parse(&buf[1..len], self.byte_order);
+        block.count = block.count.min(self.max_points);
+        let data = self.state.process(block);
+        Ok((self.record(data), gesture))
+    }
+
+    /// Mark every cached touch point as released without touching the bus
+    ///
+    /// Use this when the application stops tracking touches mid-gesture (for
+    /// example on a focus or screen change) and needs a lift event the hardware
+    /// never delivered. Only the driver's cache is modified: `touch_count` is set
+    /// to 0 and both points become [`TouchStatus::Release`], keeping their last
+    /// coordinates. A finger that is still down will be reported as a fresh
+    /// [`TouchStatus::Touch`] by the next scan.
+    ///
+    /// # Returns
+    /// The synthesized release frame
+    pub fn release_all(&mut self) -> TouchData {
+        let mut data = self.state.release_all();
+        data.seq = self.seq;
+        data
+    }
+
+    /// Call `on_event` for each change between `prev` and the cached frame
+    ///
+    /// A callback alternative to [`TouchData::as_events`]: the cached frame is
+    /// the one returned by the last scan, and events are produced in the same
+    /// order. Nothing is allocated and the bus is not touched.
+    ///
+    /// # Arguments
+    /// * `prev` - The frame to compare against, usually the previous scan result
+    /// * `on_event` - Called once per event
+    pub fn dispatch(&self, prev: &TouchData, on_event: impl FnMut(Event)) {
+        self.state.frame().as_events(prev).for_each(on_event);
+    }
+
+    /// Choose how reported touch points are assigned to slots
+    ///
+    /// With the default [`TrackingMode::HardwareId`] each point goes to the
+    /// slot given by its touch ID, so a finger keeps its slot from press to
+    /// release. Panels whose firmware reports unreliable IDs can switch to
+    /// [`TrackingMode::Positional`], which fills the slots in report order:
+    /// it is immune to ID collisions and renumbering, but a finger may change
+    /// slot when another one lifts, so per-slot events no longer follow one
+    /// finger. Cached points keep their slot across the switch.
+    ///
+    /// # Arguments
+    /// * `mode` - How points are assigned to slots
+    pub fn set_tracking(&mut self, mode: TrackingMode) {
+        self.state.tracking = mode;
+    }
+
+    /// Number of scans where both touch points reported the same touch ID
+    ///
+    /// Some firmware reports ID 0 for both points while two fingers are down.
+    /// Scans work around this by storing the points in report order, and
+    /// count each occurrence here so a misbehaving panel can be spotted. The
+    /// count wraps on overflow.
+    pub fn id_collision_count(&self) -> u32 {
+        self.state.id_collisions
+    }
+
+    /// Whether the last scan followed an interrupt or was a plain poll
+    ///
+    /// [`ScanSource::Interrupt`] after [`Self::wait_for_touch`], and
+    /// [`ScanSource::Poll`] after any other scan method. Tracked by the driver
+    /// alone, so no bus traffic is involved. Useful for confirming in the
+    /// field that an interrupt path is firing rather than falling back to
+    /// polling.
+    pub fn last_scan_source(&self) -> ScanSource {
+        self.last_source
+    }
+
+    /// Frames returned by the most recent scans, oldest first
+    ///
+    /// Holds up to `HISTORY` frames, appended by every scan method, so
+    /// velocity and gesture code can look back without keeping a buffer of
+    /// its own. Once full, each scan drops the oldest frame. A driver created
+    /// with [`FT6336U::new`] has a capacity of 0 and always returns an empty
+    /// slice. The history is cleared by a reset but not by
+    /// [`Self::release_all`], which doesn't scan.
+    pub fn history(&self) -> &[TouchData] {
+        self.history.as_slice()
+    }
+
+    /// Number of frames currently held in the history
+    ///
+    /// Grows with each scan until it reaches `HISTORY`.
+    pub fn history_len(&self) -> usize {
+        self.history.as_slice().len()
+    }
+
+    /// Forget every frame in the history
+    ///
+    /// Useful when the application starts a new interaction and older frames
+    /// should not feed into velocity or gesture calculations.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl<I2C, const HISTORY: usize> TouchController for FT6336U<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    type Error = Error<I2C::Error>;
+
+    async fn scan(&mut self) -> Result<TouchData, Self::Error> {
+        FT6336U::scan(self).await
+    }
+
+    fn max_points(&self) -> usize {
+        self.max_touch_points()
+    }
+
+    async fn set_threshold(&mut self, threshold: u8) -> Result<(), Self::Error> {
+        self.write_touch_threshold(threshold).await
+    }
 
-        Ok(self.touch_data)
+    async fn set_report_rate(&mut self, rate: u8) -> Result<(), Self::Error> {
+        self.write_active_rate(rate).await
     }
 }
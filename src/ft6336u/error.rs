@@ -5,6 +5,9 @@
 
 /// Errors that can occur during FT6336U operations
 ///
+/// This enum is `#[non_exhaustive]`: new variants may be added in minor
+/// releases, so matches outside this crate need a wildcard arm.
+///
 /// # Examples
 ///
 /// ```rust
@@ -12,13 +15,35 @@
 ///
 /// // The error type is generic over the I2C error type
 /// let err: Error<()> = Error::InvalidData;
+///
+/// match err {
+///     Error::I2c(_) => println!("bus error"),
+///     Error::InvalidData => println!("unexpected register value"),
+///     _ => println!("other error"),
+/// }
 /// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error<E> {
     /// I2C communication error
     I2c(E),
     /// Invalid data received from device
     InvalidData,
+    /// Error driving a GPIO pin (e.g. the reset line)
+    Pin,
+    /// The device did not reach the expected state before the deadline
+    ///
+    /// Returned by the polling helpers that take a timeout: `init_ready()`
+    /// (the chip ID never matched), `recalibrate()` (calibration never
+    /// finished), the factory data reads `read_factory_raw_data()` and
+    /// `read_raw_diff()` (the factory scan never completed),
+    /// `wait_for_release()` (a finger stayed down) and `wait_for_touch()` in
+    /// the blocking driver (the INT pin never went low).
+    Timeout,
+    /// A device answered but reported an unexpected chip ID
+    WrongChipId(u8),
+    /// No device acknowledged the FT6336U address
+    NotPresent,
 }
 
 impl<E> From<E> for Error<E> {
@@ -26,3 +51,30 @@ impl<E> From<E> for Error<E> {
         Self::I2c(e)
     }
 }
+
+/// Lets the driver's error be returned from `embedded-hal` digital traits,
+/// such as the [`InputPin`](embedded_hal::digital::InputPin) implementation of
+/// `TouchInput`
+impl<E: core::fmt::Debug> embedded_hal::digital::Error for Error<E> {
+    fn kind(&self) -> embedded_hal::digital::ErrorKind {
+        embedded_hal::digital::ErrorKind::Other
+    }
+}
+
+/// A raw value that doesn't correspond to any variant of an enum
+///
+/// The error of the `TryFrom<u8>` conversions for the driver's register enums,
+/// such as [`CtrlMode`](super::CtrlMode) and [`GestureMode`](super::GestureMode).
+/// Holds the rejected value.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{CtrlMode, InvalidEnumValue};
+///
+/// let mode: Result<CtrlMode, _> = 1u8.try_into();
+/// assert_eq!(mode, Ok(CtrlMode::SwitchToMonitor));
+/// assert_eq!(CtrlMode::try_from(7), Err(InvalidEnumValue(7)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidEnumValue(pub u8);
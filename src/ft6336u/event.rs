@@ -0,0 +1,276 @@
+//! High-level touch events derived by comparing two touch frames.
+//!
+//! This module contains the [`Event`] enum, the allocation-free iterator
+//! returned by [`TouchData::as_events`], the per-slot
+//! [`TouchData::changed_points`] check, and the per-slot movement from
+//! [`TouchData::delta_since`].
+
+use super::types::{Coord, TouchData};
+
+/// Maximum number of events produced by diffing two frames
+const MAX_EVENTS: usize = 4;
+
+/// A change in touch state between two frames
+///
+/// `id` is the slot index in [`TouchData::points`]. The hardware touch ID of
+/// the point in that slot is in [`TouchPoint::id`](super::types::TouchPoint::id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event {
+    /// A finger touched down
+    Down {
+        /// Touch slot
+        id: u8,
+        /// X coordinate
+        x: Coord,
+        /// Y coordinate
+        y: Coord,
+    },
+    /// A finger that was already down moved
+    Move {
+        /// Touch slot
+        id: u8,
+        /// X coordinate
+        x: Coord,
+        /// Y coordinate
+        y: Coord,
+    },
+    /// A finger lifted
+    Up {
+        /// Touch slot
+        id: u8,
+    },
+    /// A second finger touched down while the first was held
+    ///
+    /// Fires once, when the number of active points goes from one to two,
+    /// right after the [`Event::Down`] for the new finger. Two fingers landing
+    /// in the same frame don't produce it.
+    SecondDown {
+        /// X coordinate of the new finger
+        x: Coord,
+        /// Y coordinate of the new finger
+        y: Coord,
+    },
+}
+
+/// Iterator over the events between two frames
+///
+/// Returned by [`TouchData::as_events`]. Events are stored inline, so no
+/// allocation is needed.
+#[derive(Debug, Clone)]
+pub struct EventIter {
+    /// Collected events; only the first `len` are valid
+    events: [Option<Event>; MAX_EVENTS],
+    /// Number of collected events
+    len: usize,
+    /// Index of the next event to yield
+    pos: usize,
+}
+
+impl EventIter {
+    /// Create an empty iterator
+    fn new() -> Self {
+        Self {
+            events: [None; MAX_EVENTS],
+            len: 0,
+            pos: 0,
+        }
+    }
+
+    /// Append an event
+    fn push(&mut self, event: Event) {
+        if self.len < MAX_EVENTS {
+            self.events[self.len] = Some(event);
+            self.len += 1;
+        }
+    }
+}
+
+impl Iterator for EventIter {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        if self.pos >= self.len {
+            return None;
+        }
+        let event = self.events[self.pos];
+        self.pos += 1;
+        event
+    }
+}
+
+impl TouchData {
+    /// Compute the events that turn `prev` into this frame
+    ///
+    /// Each slot is compared independently: a slot that became active yields
+    /// [`Event::Down`], one that stayed active at a new position yields
+    /// [`Event::Move`], and one that became inactive yields [`Event::Up`].
+    /// Events are ordered by slot. When a second finger joins one already
+    /// down, [`Event::SecondDown`] follows the slot events.
+    ///
+    /// # Arguments
+    /// * `prev` - The previous frame
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{Event, TouchData, TouchStatus};
+    ///
+    /// let prev = TouchData::default();
+    /// let mut data = TouchData::default();
+    /// data.touch_count = 1;
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].x = 10;
+    /// data.points[0].y = 20;
+    ///
+    /// let mut events = data.as_events(&prev);
+    /// assert_eq!(events.next(), Some(Event::Down { id: 0, x: 10, y: 20 }));
+    /// assert_eq!(events.next(), None);
+    ///
+    /// let mut moved = data;
+    /// moved.points[0].status = TouchStatus::Stream;
+    /// moved.points[0].x = 15;
+    /// assert_eq!(
+    ///     moved.as_events(&data).collect::<Vec<_>>(),
+    ///     [Event::Move { id: 0, x: 15, y: 20 }]
+    /// );
+    ///
+    /// let lifted = TouchData::default();
+    /// assert_eq!(lifted.as_events(&moved).collect::<Vec<_>>(), [Event::Up { id: 0 }]);
+    /// ```
+    ///
+    /// A second finger landing while the first is held:
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{Event, TouchData, TouchStatus};
+    ///
+    /// let mut first = TouchData::default();
+    /// first.touch_count = 1;
+    /// first.points[0].status = TouchStatus::Touch;
+    /// first.points[0].x = 300;
+    /// first.points[0].y = 200;
+    ///
+    /// // The first finger is held in place as the second lands
+    /// let mut both = first;
+    /// both.touch_count = 2;
+    /// both.points[0].status = TouchStatus::Stream;
+    /// both.points[1].status = TouchStatus::Touch;
+    /// both.points[1].x = 100;
+    /// both.points[1].y = 400;
+    ///
+    /// assert_eq!(
+    ///     both.as_events(&first).collect::<Vec<_>>(),
+    ///     [
+    ///         Event::Down { id: 1, x: 100, y: 400 },
+    ///         Event::SecondDown { x: 100, y: 400 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn as_events(&self, prev: &TouchData) -> EventIter {
+        let mut events = EventIter::new();
+        let mut joined = None;
+        for (id, (cur, old)) in self.points.iter().zip(prev.points.iter()).enumerate() {
+            let id = id as u8;
+            match (old.is_active(), cur.is_active()) {
+                (false, true) => {
+                    joined = Some((cur.x, cur.y));
+                    events.push(Event::Down {
+                        id,
+                        x: cur.x,
+                        y: cur.y,
+                    });
+                }
+                (true, true) if (cur.x, cur.y) != (old.x, old.y) => events.push(Event::Move {
+                    id,
+                    x: cur.x,
+                    y: cur.y,
+                }),
+                (true, false) => events.push(Event::Up { id }),
+                _ => {}
+            }
+        }
+        if let Some((x, y)) = joined {
+            if prev.active_count() == 1 && self.active_count() == 2 {
+                events.push(Event::SecondDown { x, y });
+            }
+        }
+        events
+    }
+
+    /// Which point slots differ from `prev`
+    ///
+    /// A slot counts as changed if any of its fields differ, including its
+    /// status and weight. Points filled in by `scan_at()` also change whenever
+    /// `pressed_ms` advances. Useful for redrawing only the parts of a UI that
+    /// track a changed finger.
+    ///
+    /// # Arguments
+    /// * `prev` - The previous frame
+    ///
+    /// # Returns
+    /// `true` for each slot that changed, indexed by slot
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let prev = TouchData::default();
+    /// let mut data = prev;
+    /// assert_eq!(data.changed_points(&prev), [false, false]);
+    ///
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].x = 50;
+    /// assert_eq!(data.changed_points(&prev), [false, true]);
+    /// ```
+    pub fn changed_points(&self, prev: &TouchData) -> [bool; 2] {
+        [
+            self.points[0] != prev.points[0],
+            self.points[1] != prev.points[1],
+        ]
+    }
+
+    /// Movement of each slot since `prev`
+    ///
+    /// Slots are compared independently, so two fingers can be dragged at
+    /// once. Differences beyond the `i16` range saturate.
+    ///
+    /// # Arguments
+    /// * `prev` - The previous frame
+    ///
+    /// # Returns
+    /// `(dx, dy)` for each slot that is active in both frames, `None` for the
+    /// others, indexed by slot
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut prev = TouchData::default();
+    /// prev.points[0].status = TouchStatus::Touch;
+    /// prev.points[0].x = 100;
+    /// prev.points[0].y = 100;
+    ///
+    /// let mut data = prev;
+    /// data.points[0].status = TouchStatus::Stream;
+    /// data.points[0].x = 90;
+    /// data.points[0].y = 115;
+    /// data.points[1].status = TouchStatus::Touch;
+    ///
+    /// // Slot 1 only just touched down, so it has no movement yet
+    /// assert_eq!(data.delta_since(&prev), [Some((-10, 15)), None]);
+    /// ```
+    pub fn delta_since(&self, prev: &TouchData) -> [Option<(i16, i16)>; 2] {
+        let delta = |cur: Coord, old: Coord| {
+            (cur as i32 - old as i32).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+        };
+        let mut deltas = [None; 2];
+        for (slot, (cur, old)) in self.points.iter().zip(prev.points.iter()).enumerate() {
+            if cur.is_active() && old.is_active() {
+                deltas[slot] = Some((delta(cur.x, old.x), delta(cur.y, old.y)));
+            }
+        }
+        deltas
+    }
+}
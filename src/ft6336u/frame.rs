@@ -0,0 +1,85 @@
+//! Fixed-size byte encoding of [`TouchData`] for simple wire protocols.
+//!
+//! This is a lighter alternative to `serde` when touches need to be sent to a
+//! host over UART or similar links. The layout is fixed and documented on
+//! [`TouchData::to_bytes`] so the receiving side can be written in any
+//! language.
+
+use super::types::{Coord, TouchData, TouchStatus};
+
+/// Bytes used by one encoded point: status, X, Y, weight
+const POINT_LEN: usize = 6;
+
+impl TouchData {
+    /// Length of the frame produced by [`Self::to_bytes`]
+    pub const FRAME_LEN: usize = 1 + 2 * POINT_LEN;
+
+    /// Encode this frame as a fixed-size byte array
+    ///
+    /// Multi-byte values are little-endian. The layout is:
+    ///
+    /// | Offset | Size | Field                                              |
+    /// |--------|------|----------------------------------------------------|
+    /// | 0      | 1    | `touch_count`                                      |
+    /// | 1      | 1    | Point 0 status: 0 = Touch, 1 = Stream, 2 = Release |
+    /// | 2      | 2    | Point 0 X                                          |
+    /// | 4      | 2    | Point 0 Y                                          |
+    /// | 6      | 1    | Point 0 weight                                     |
+    /// | 7      | 6    | Point 1, same layout as point 0                    |
+    ///
+    /// `pressed_ms`, `raw_event`, `id` and `seq` are not encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// data.touch_count = 1;
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].x = 0x0123;
+    /// data.points[0].y = 0x0456;
+    /// data.points[0].weight = 0x20;
+    ///
+    /// let bytes = data.to_bytes();
+    /// assert_eq!(bytes[..7], [1, 0, 0x23, 0x01, 0x56, 0x04, 0x20]);
+    ///
+    /// let decoded = TouchData::from_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded, data);
+    /// ```
+    pub fn to_bytes(&self) -> [u8; Self::FRAME_LEN] {
+        let mut buf = [0u8; Self::FRAME_LEN];
+        buf[0] = self.touch_count;
+        for (point, chunk) in self.points.iter().zip(buf[1..].chunks_exact_mut(POINT_LEN)) {
+            chunk[0] = point.status.as_u8();
+            chunk[1..3].copy_from_slice(&point.x.to_le_bytes());
+            chunk[3..5].copy_from_slice(&point.y.to_le_bytes());
+            chunk[5] = point.weight;
+        }
+        buf
+    }
+
+    /// Decode a frame produced by [`Self::to_bytes`]
+    ///
+    /// Fields that aren't encoded are left at their defaults.
+    ///
+    /// # Returns
+    /// The decoded frame, or `None` if the touch count is above 2 or a status
+    /// byte is unknown
+    pub fn from_bytes(buf: &[u8; Self::FRAME_LEN]) -> Option<TouchData> {
+        if buf[0] > 2 {
+            return None;
+        }
+        let mut data = TouchData {
+            touch_count: buf[0],
+            ..Default::default()
+        };
+        for (point, chunk) in data.points.iter_mut().zip(buf[1..].chunks_exact(POINT_LEN)) {
+            point.status = TouchStatus::from_u8(chunk[0])?;
+            point.x = Coord::from_le_bytes([chunk[1], chunk[2]]);
+            point.y = Coord::from_le_bytes([chunk[3], chunk[4]]);
+            point.weight = chunk[5];
+        }
+        Some(data)
+    }
+}
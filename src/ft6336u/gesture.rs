@@ -0,0 +1,769 @@
+//! Software gesture detectors built on top of [`TouchData`] frames.
+//!
+//! The FT6336U's built-in gesture engine only reports a handful of fixed
+//! gestures. The detectors in this module work on the frames returned by
+//! `scan()` instead, so they are independent of firmware support. They only use
+//! integer math and hold no references to the driver, so they can be fed from
+//! either the blocking or the async API.
+//!
+//! [`GestureLog`] queues the hardware gestures reported by
+//! `scan_with_gesture()` for later processing.
+
+use super::math::{atan2_deg, isqrt, squared_distance};
+use super::types::{Coord, Gesture, TouchData};
+
+/// Event emitted by [`TwoFingerGesture`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoFingerEvent {
+    /// The distance between the fingers changed more than the centroid moved
+    Pinch {
+        /// New finger distance relative to the previous event, in thousandths
+        /// (1000 = unchanged, below 1000 = pinch in, above 1000 = pinch out)
+        scale: u32,
+    },
+    /// The centroid of the fingers moved more than their distance changed
+    Pan {
+        /// Horizontal centroid movement since the previous event
+        dx: i16,
+        /// Vertical centroid movement since the previous event
+        dy: i16,
+    },
+}
+
+/// Classifies two-finger interactions as pinch or pan
+///
+/// Feed it every scanned frame with [`TwoFingerGesture::update`]. While two
+/// points are active it compares the change in finger distance with the
+/// movement of their centroid since the last emitted event, and reports
+/// whichever is larger once it exceeds the dead zone. Movement below the dead
+/// zone accumulates until it crosses it. The detector resets as soon as fewer
+/// than two points are active.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::gesture::{TwoFingerEvent, TwoFingerGesture};
+/// use ft6336u_driver::{Coord, TouchData, TouchStatus};
+///
+/// fn frame(a: (Coord, Coord), b: (Coord, Coord)) -> TouchData {
+///     let mut data = TouchData::default();
+///     data.touch_count = 2;
+///     for (point, (x, y)) in data.points.iter_mut().zip([a, b]) {
+///         point.status = TouchStatus::Stream;
+///         point.x = x;
+///         point.y = y;
+///     }
+///     data
+/// }
+///
+/// let mut detector = TwoFingerGesture::new(5);
+///
+/// // The first two-finger frame only sets the baseline
+/// assert_eq!(detector.update(&frame((100, 100), (200, 100))), None);
+///
+/// // Fingers spread apart: distance 100 -> 200
+/// assert_eq!(
+///     detector.update(&frame((50, 100), (250, 100))),
+///     Some(TwoFingerEvent::Pinch { scale: 2000 })
+/// );
+///
+/// // Both fingers move down together
+/// assert_eq!(
+///     detector.update(&frame((50, 130), (250, 130))),
+///     Some(TwoFingerEvent::Pan { dx: 0, dy: 30 })
+/// );
+///
+/// // Jitter inside the dead zone is ignored
+/// assert_eq!(detector.update(&frame((51, 131), (250, 130))), None);
+///
+/// // A pan across the whole coordinate range saturates rather than wrapping
+/// detector.reset();
+/// detector.update(&frame((0, 0), (0, 0)));
+/// assert_eq!(
+///     detector.update(&frame((Coord::MAX, 0), (Coord::MAX, 0))),
+///     Some(TwoFingerEvent::Pan { dx: i16::MAX, dy: 0 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TwoFingerGesture {
+    /// Minimum change, in touch counts, before an event is emitted
+    dead_zone: u16,
+    /// Finger distance and centroid at the last emitted event
+    baseline: Option<(u16, (Coord, Coord))>,
+}
+
+impl TwoFingerGesture {
+    /// Create a new detector
+    ///
+    /// # Arguments
+    /// * `dead_zone` - Minimum distance or centroid change, in touch counts, to report
+    pub fn new(dead_zone: u16) -> Self {
+        Self {
+            dead_zone,
+            baseline: None,
+        }
+    }
+
+    /// Forget the current baseline
+    pub fn reset(&mut self) {
+        self.baseline = None;
+    }
+
+    /// Process one scanned frame
+    ///
+    /// # Returns
+    /// A pinch or pan event if the movement since the last event exceeds the dead zone
+    pub fn update(&mut self, data: &TouchData) -> Option<TwoFingerEvent> {
+        let (Some(distance), Some(centroid)) = (data.pinch_distance(), data.centroid()) else {
+            self.reset();
+            return None;
+        };
+
+        let Some((prev_distance, prev_centroid)) = self.baseline else {
+            self.baseline = Some((distance, centroid));
+            return None;
+        };
+
+        let distance_change = distance.abs_diff(prev_distance);
+        let dx = (i32::from(centroid.0) - i32::from(prev_centroid.0))
+            .clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let dy = (i32::from(centroid.1) - i32::from(prev_centroid.1))
+            .clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+        let centroid_change = dx.unsigned_abs().max(dy.unsigned_abs());
+
+        if distance_change.max(centroid_change) <= self.dead_zone {
+            return None;
+        }
+
+        self.baseline = Some((distance, centroid));
+        if distance_change > centroid_change && prev_distance > 0 {
+            Some(TwoFingerEvent::Pinch {
+                scale: distance as u32 * 1000 / prev_distance as u32,
+            })
+        } else {
+            Some(TwoFingerEvent::Pan { dx, dy })
+        }
+    }
+}
+
+/// Event emitted by [`LongPressDetector`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LongPress {
+    /// X coordinate where the press started
+    pub x: Coord,
+    /// Y coordinate where the press started
+    pub y: Coord,
+}
+
+/// Detects press-and-hold on the primary touch point
+///
+/// Feed it every scanned frame along with a millisecond timestamp from any
+/// monotonic time source. When the primary point (see [`TouchData::primary`])
+/// stays down for at least the hold duration without leaving the movement
+/// radius around where it started, a single [`LongPress`] is emitted. The
+/// detector re-arms when the finger is released, or restarts the hold from the
+/// new position when the finger moves beyond the radius.
+///
+/// Timestamps may wrap around; only differences between them are used.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::gesture::{LongPress, LongPressDetector};
+/// use ft6336u_driver::{TouchData, TouchStatus};
+///
+/// let mut data = TouchData::default();
+/// data.touch_count = 1;
+/// data.points[0].status = TouchStatus::Touch;
+/// data.points[0].x = 100;
+/// data.points[0].y = 100;
+///
+/// let mut detector = LongPressDetector::new(500, 10);
+///
+/// assert_eq!(detector.update(&data, 0), None);
+/// data.points[0].status = TouchStatus::Stream;
+/// data.points[0].x = 104;
+/// assert_eq!(detector.update(&data, 300), None);
+/// assert_eq!(detector.update(&data, 500), Some(LongPress { x: 100, y: 100 }));
+///
+/// // Only reported once per press
+/// assert_eq!(detector.update(&data, 900), None);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LongPressDetector {
+    /// How long the point must be held, in milliseconds
+    hold_ms: u32,
+    /// How far the point may move from where it started, in touch counts
+    max_movement: u16,
+    /// Where and when the current hold started
+    anchor: Option<(Coord, Coord, u32)>,
+    /// Whether the current hold has already been reported
+    fired: bool,
+}
+
+impl LongPressDetector {
+    /// Create a new detector
+    ///
+    /// # Arguments
+    /// * `hold_ms` - Minimum hold duration in milliseconds
+    /// * `max_movement` - Maximum distance from the start position, in touch counts
+    pub fn new(hold_ms: u32, max_movement: u16) -> Self {
+        Self {
+            hold_ms,
+            max_movement,
+            anchor: None,
+            fired: false,
+        }
+    }
+
+    /// Forget any press in progress
+    pub fn reset(&mut self) {
+        self.anchor = None;
+        self.fired = false;
+    }
+
+    /// Process one scanned frame
+    ///
+    /// # Arguments
+    /// * `data` - The frame returned by `scan()`
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// A long press the first time the hold duration is reached
+    pub fn update(&mut self, data: &TouchData, now_ms: u32) -> Option<LongPress> {
+        let Some(point) = data.primary() else {
+            self.reset();
+            return None;
+        };
+
+        let Some((x, y, start)) = self.anchor else {
+            self.anchor = Some((point.x, point.y, now_ms));
+            return None;
+        };
+
+        let radius = self.max_movement as u64;
+        if squared_distance((point.x, point.y), (x, y)) > radius * radius {
+            self.anchor = Some((point.x, point.y, now_ms));
+            self.fired = false;
+            return None;
+        }
+
+        if !self.fired && now_ms.wrapping_sub(start) >= self.hold_ms {
+            self.fired = true;
+            return Some(LongPress { x, y });
+        }
+        None
+    }
+}
+
+/// Event emitted by [`TapDetector`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapEvent {
+    /// A short press and release without moving
+    Tap {
+        /// X coordinate where the press started
+        x: Coord,
+        /// Y coordinate where the press started
+        y: Coord,
+    },
+    /// A second tap close to the previous one, in place and in time
+    DoubleTap {
+        /// X coordinate where the second press started
+        x: Coord,
+        /// Y coordinate where the second press started
+        y: Coord,
+    },
+}
+
+/// Detects taps and double taps on the primary touch point
+///
+/// Feed it every scanned frame along with a millisecond timestamp. A press
+/// counts as a tap when the primary point lifts within `max_tap_ms` of touching
+/// down and never moves further than `max_tap_movement` from where it started;
+/// anything longer is a hold and anything further is a drag, and neither is
+/// reported. A tap that starts within `double_tap_gap_ms` of the previous
+/// tap's release, and within `max_tap_movement` of it, is reported as
+/// [`TapEvent::DoubleTap`] instead. The first tap of a pair is still reported
+/// as a [`TapEvent::Tap`] when it happens, since the detector can't wait to
+/// see whether a second one follows.
+///
+/// The defaults are 200ms, 10 counts and 300ms.
+///
+/// # Running Alongside `LongPressDetector`
+///
+/// Both detectors can consume the same frames. Keep `max_tap_ms` below the
+/// [`LongPressDetector`]'s hold duration and a press can never be both: a long
+/// press fires while the finger is still down, by which time the press is too
+/// long to count as a tap when it lifts.
+///
+/// Timestamps may wrap around; only differences between them are used.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::gesture::{LongPressDetector, TapDetector, TapEvent};
+/// use ft6336u_driver::{Coord, TouchData, TouchStatus};
+///
+/// fn down(x: Coord, y: Coord) -> TouchData {
+///     let mut data = TouchData::default();
+///     data.touch_count = 1;
+///     data.points[0].status = TouchStatus::Stream;
+///     data.points[0].x = x;
+///     data.points[0].y = y;
+///     data
+/// }
+/// let up = TouchData::default();
+///
+/// let mut taps = TapDetector::new().with_max_tap_ms(150);
+/// let mut holds = LongPressDetector::new(500, 10);
+///
+/// // A tap
+/// assert_eq!(taps.update(&down(100, 100), 0), None);
+/// assert_eq!(taps.update(&up, 80), Some(TapEvent::Tap { x: 100, y: 100 }));
+///
+/// // A second tap soon after, nearby
+/// assert_eq!(taps.update(&down(103, 101), 200), None);
+/// assert_eq!(taps.update(&up, 260), Some(TapEvent::DoubleTap { x: 103, y: 101 }));
+///
+/// // A long press is not a tap
+/// assert_eq!(taps.update(&down(50, 50), 1000), None);
+/// assert_eq!(holds.update(&down(50, 50), 1000), None);
+/// assert_eq!(taps.update(&down(50, 50), 1500), None);
+/// assert!(holds.update(&down(50, 50), 1500).is_some());
+/// assert_eq!(taps.update(&up, 1600), None);
+///
+/// // Neither is a quick drag
+/// assert_eq!(taps.update(&down(50, 50), 2000), None);
+/// assert_eq!(taps.update(&down(90, 50), 2050), None);
+/// assert_eq!(taps.update(&up, 2100), None);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TapDetector {
+    /// Longest press that still counts as a tap, in milliseconds
+    max_tap_ms: u32,
+    /// How far the point may move during a tap, in touch counts
+    max_tap_movement: u16,
+    /// Longest gap between a tap and the start of the next for a double tap
+    double_tap_gap_ms: u32,
+    /// Where and when the current press started, and whether it can still be a tap
+    press: Option<(Coord, Coord, u32, bool)>,
+    /// Where and when the last single tap was released
+    last_tap: Option<(Coord, Coord, u32)>,
+}
+
+impl TapDetector {
+    /// Create a detector with the default thresholds
+    pub fn new() -> Self {
+        Self {
+            max_tap_ms: 200,
+            max_tap_movement: 10,
+            double_tap_gap_ms: 300,
+            press: None,
+            last_tap: None,
+        }
+    }
+
+    /// Set the longest press that still counts as a tap, in milliseconds
+    pub fn with_max_tap_ms(mut self, max_tap_ms: u32) -> Self {
+        self.max_tap_ms = max_tap_ms;
+        self
+    }
+
+    /// Set how far the point may move during a tap, in touch counts
+    pub fn with_max_tap_movement(mut self, max_tap_movement: u16) -> Self {
+        self.max_tap_movement = max_tap_movement;
+        self
+    }
+
+    /// Set the longest gap between two taps of a double tap, in milliseconds
+    pub fn with_double_tap_gap_ms(mut self, double_tap_gap_ms: u32) -> Self {
+        self.double_tap_gap_ms = double_tap_gap_ms;
+        self
+    }
+
+    /// Forget any press or tap in progress
+    pub fn reset(&mut self) {
+        self.press = None;
+        self.last_tap = None;
+    }
+
+    /// Whether `(x, y)` is within the tap movement radius of `(ax, ay)`
+    fn near(&self, (x, y): (Coord, Coord), (ax, ay): (Coord, Coord)) -> bool {
+        let radius = self.max_tap_movement as u64;
+        squared_distance((x, y), (ax, ay)) <= radius * radius
+    }
+
+    /// Process one scanned frame
+    ///
+    /// # Arguments
+    /// * `data` - The frame returned by `scan()`
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// A tap or double tap on the frame where the finger lifts
+    pub fn update(&mut self, data: &TouchData, now_ms: u32) -> Option<TapEvent> {
+        if let Some(point) = data.primary() {
+            let pos = (point.x, point.y);
+            self.press = Some(match self.press {
+                None => (pos.0, pos.1, now_ms, true),
+                Some((x, y, start, tap)) => {
+                    let quick = now_ms.wrapping_sub(start) <= self.max_tap_ms;
+                    (x, y, start, tap && quick && self.near(pos, (x, y)))
+                }
+            });
+            return None;
+        }
+
+        let (x, y, start, tap) = self.press.take()?;
+        if !tap || now_ms.wrapping_sub(start) > self.max_tap_ms {
+            self.last_tap = None;
+            return None;
+        }
+
+        let double = self.last_tap.take().is_some_and(|(lx, ly, released)| {
+            start.wrapping_sub(released) <= self.double_tap_gap_ms && self.near((x, y), (lx, ly))
+        });
+        if double {
+            return Some(TapEvent::DoubleTap { x, y });
+        }
+        self.last_tap = Some((x, y, now_ms));
+        Some(TapEvent::Tap { x, y })
+    }
+}
+
+impl Default for TapDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A touch point that has reported the same coordinate for too long
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StuckTouch {
+    /// Touch slot
+    pub id: u8,
+    /// X coordinate the point is stuck at
+    pub x: Coord,
+    /// Y coordinate the point is stuck at
+    pub y: Coord,
+}
+
+/// Detects touch points latched at a fixed coordinate
+///
+/// After an ESD event some panels keep reporting a touch at one position
+/// until the controller is reset. Real fingers always jitter by at least a
+/// count or two, so a point whose coordinate stays exactly the same for
+/// longer than the limit is reported as stuck. Each slot is tracked on its
+/// own, and its timer restarts whenever its coordinate changes or it is
+/// released.
+///
+/// Timestamps may wrap around; only differences between them are used.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::gesture::{StuckTouch, StuckTouchDetector};
+/// use ft6336u_driver::{TouchData, TouchStatus};
+///
+/// let mut data = TouchData::default();
+/// data.touch_count = 1;
+/// data.points[0].status = TouchStatus::Stream;
+/// data.points[0].x = 50;
+/// data.points[0].y = 60;
+///
+/// let mut detector = StuckTouchDetector::new(5000);
+///
+/// assert_eq!(detector.update(&data, 0), None);
+/// assert_eq!(detector.update(&data, 4000), None);
+/// assert_eq!(
+///     detector.update(&data, 5000),
+///     Some(StuckTouch { id: 0, x: 50, y: 60 })
+/// );
+///
+/// // Any movement restarts the timer
+/// data.points[0].x = 51;
+/// assert_eq!(detector.update(&data, 6000), None);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StuckTouchDetector {
+    /// How long a coordinate may stay unchanged, in milliseconds
+    limit_ms: u32,
+    /// Position and time each slot first reported its current coordinate
+    since: [Option<(Coord, Coord, u32)>; 2],
+}
+
+impl StuckTouchDetector {
+    /// Create a new detector
+    ///
+    /// # Arguments
+    /// * `limit_ms` - How long a point may report an identical coordinate, in milliseconds
+    pub fn new(limit_ms: u32) -> Self {
+        Self {
+            limit_ms,
+            since: [None; 2],
+        }
+    }
+
+    /// Forget all tracked points
+    pub fn reset(&mut self) {
+        self.since = [None; 2];
+    }
+
+    /// Process one scanned frame
+    ///
+    /// # Arguments
+    /// * `data` - The frame returned by `scan()`
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// The first stuck point, on every frame for as long as it stays stuck
+    pub fn update(&mut self, data: &TouchData, now_ms: u32) -> Option<StuckTouch> {
+        let mut stuck = None;
+        for (id, (point, since)) in data.points.iter().zip(self.since.iter_mut()).enumerate() {
+            if !point.is_active() {
+                *since = None;
+                continue;
+            }
+            match *since {
+                Some((x, y, start)) if (x, y) == (point.x, point.y) => {
+                    if stuck.is_none() && now_ms.wrapping_sub(start) >= self.limit_ms {
+                        stuck = Some(StuckTouch { id: id as u8, x, y });
+                    }
+                }
+                _ => *since = Some((point.x, point.y, now_ms)),
+            }
+        }
+        stuck
+    }
+}
+
+/// Cardinal direction of a [`Swipe`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    /// Toward smaller Y
+    Up,
+    /// Toward larger Y
+    Down,
+    /// Toward smaller X
+    Left,
+    /// Toward larger X
+    Right,
+}
+
+/// Event emitted by [`SwipeDetector`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Swipe {
+    /// Closest cardinal direction
+    pub direction: SwipeDirection,
+    /// Angle in degrees (0-359), counter-clockwise from rightward, so 90 is up
+    ///
+    /// Computed with an integer `atan2` approximation that is within one
+    /// degree of the exact angle.
+    pub angle: u16,
+    /// Straight-line distance from press to release, in touch counts,
+    /// saturating at `u16::MAX`
+    pub distance: u16,
+}
+
+/// Detects one-finger swipes on the primary touch point
+///
+/// Feed it every scanned frame. The position where the primary point (see
+/// [`TouchData::primary`]) touched down is remembered, and when it lifts a
+/// [`Swipe`] is reported if it ended at least the minimum distance away. The
+/// angle makes the detector usable for knob-style or circular controls, not
+/// only four-way navigation, without floating point or `libm`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::gesture::{Swipe, SwipeDetector, SwipeDirection};
+/// use ft6336u_driver::{TouchData, TouchStatus};
+///
+/// let mut data = TouchData::default();
+/// data.touch_count = 1;
+/// data.points[0].status = TouchStatus::Touch;
+/// data.points[0].x = 100;
+/// data.points[0].y = 200;
+///
+/// let mut detector = SwipeDetector::new(50);
+/// assert_eq!(detector.update(&data), None);
+///
+/// // Drag up and to the right, then lift
+/// data.points[0].status = TouchStatus::Stream;
+/// data.points[0].x = 200;
+/// data.points[0].y = 100;
+/// assert_eq!(detector.update(&data), None);
+///
+/// data.touch_count = 0;
+/// data.points[0].status = TouchStatus::Release;
+/// assert_eq!(
+///     detector.update(&data),
+///     Some(Swipe { direction: SwipeDirection::Up, angle: 45, distance: 141 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SwipeDetector {
+    /// Minimum distance from press to release, in touch counts
+    min_distance: u16,
+    /// Where the current press started and where it was last seen
+    track: Option<((Coord, Coord), (Coord, Coord))>,
+}
+
+impl SwipeDetector {
+    /// Create a new detector
+    ///
+    /// # Arguments
+    /// * `min_distance` - Minimum distance from press to release, in touch counts
+    pub fn new(min_distance: u16) -> Self {
+        Self {
+            min_distance,
+            track: None,
+        }
+    }
+
+    /// Forget any press in progress
+    pub fn reset(&mut self) {
+        self.track = None;
+    }
+
+    /// Process one scanned frame
+    ///
+    /// # Returns
+    /// A swipe when the finger lifts at least the minimum distance from where it started
+    pub fn update(&mut self, data: &TouchData) -> Option<Swipe> {
+        if let Some(point) = data.primary() {
+            let pos = (point.x, point.y);
+            let start = self.track.map_or(pos, |(start, _)| start);
+            self.track = Some((start, pos));
+            return None;
+        }
+
+        let (start, end) = self.track.take()?;
+        let dx = end.0 as i32 - start.0 as i32;
+        let dy = end.1 as i32 - start.1 as i32;
+        let squared = u32::try_from(squared_distance(start, end)).unwrap_or(u32::MAX);
+        let distance = isqrt(squared);
+        if distance < self.min_distance {
+            return None;
+        }
+
+        // Screen Y grows downward, so flip it to make 90 degrees point up
+        let angle = atan2_deg(-dy, dx);
+        let direction = match angle {
+            45..=134 => SwipeDirection::Up,
+            135..=224 => SwipeDirection::Left,
+            225..=314 => SwipeDirection::Down,
+            _ => SwipeDirection::Right,
+        };
+        Some(Swipe {
+            direction,
+            angle,
+            distance,
+        })
+    }
+}
+
+/// Fixed-capacity queue of hardware gestures
+///
+/// Push the gesture from each `scan_with_gesture()` call and consume them
+/// later, decoupling gesture handling from the scan loop. [`Gesture::None`] is
+/// never stored. Once `N` gestures are queued, pushing another drops the
+/// oldest.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::gesture::GestureLog;
+/// use ft6336u_driver::Gesture;
+///
+/// let mut log = GestureLog::<2>::new();
+/// log.push(Gesture::MoveUp);
+/// log.push(Gesture::None);
+/// log.push(Gesture::ZoomIn);
+/// log.push(Gesture::MoveLeft);
+///
+/// // MoveUp was dropped to make room
+/// assert!(log.iter().eq([Gesture::ZoomIn, Gesture::MoveLeft]));
+///
+/// assert_eq!(log.pop(), Some(Gesture::ZoomIn));
+/// assert_eq!(log.drain().count(), 1);
+/// assert!(log.is_empty());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GestureLog<const N: usize> {
+    /// Ring buffer storage
+    gestures: [Gesture; N],
+    /// Index of the oldest queued gesture
+    head: usize,
+    /// Number of queued gestures
+    len: usize,
+}
+
+impl<const N: usize> GestureLog<N> {
+    /// Create an empty log
+    pub fn new() -> Self {
+        Self {
+            gestures: [Gesture::None; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Queue a gesture, ignoring [`Gesture::None`]
+    ///
+    /// If the log is full the oldest gesture is dropped.
+    pub fn push(&mut self, gesture: Gesture) {
+        if gesture == Gesture::None || N == 0 {
+            return;
+        }
+        if self.len == N {
+            self.head = (self.head + 1) % N;
+            self.len -= 1;
+        }
+        self.gestures[(self.head + self.len) % N] = gesture;
+        self.len += 1;
+    }
+
+    /// Remove and return the oldest gesture
+    pub fn pop(&mut self) -> Option<Gesture> {
+        if self.len == 0 {
+            return None;
+        }
+        let gesture = self.gestures[self.head];
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(gesture)
+    }
+
+    /// Iterate over the queued gestures, oldest first, without removing them
+    pub fn iter(&self) -> impl Iterator<Item = Gesture> + '_ {
+        (0..self.len).map(move |i| self.gestures[(self.head + i) % N])
+    }
+
+    /// Remove and yield the queued gestures, oldest first
+    pub fn drain(&mut self) -> impl Iterator<Item = Gesture> + '_ {
+        core::iter::from_fn(move || self.pop())
+    }
+
+    /// Number of queued gestures
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no gestures are queued
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Drop every queued gesture
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for GestureLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
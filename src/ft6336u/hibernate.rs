@@ -0,0 +1,106 @@
+//! Best-effort hibernation when the driver goes out of scope.
+//!
+//! [`HibernateOnDrop`] owns the blocking driver and writes the hibernate
+//! power mode from its `Drop` impl, for power-sensitive designs that want
+//! the controller parked whenever the driver is torn down.
+//!
+//! This module is only available without the `async` feature, since `Drop`
+//! can't await a transfer. With the async driver, call `hibernate().await`
+//! before dropping it instead.
+
+use core::ops::{Deref, DerefMut};
+
+use embedded_hal::i2c::I2c;
+
+use super::driver::FT6336U;
+
+/// A driver that hibernates the controller when dropped
+///
+/// Created by the driver's `hibernate_on_drop()` method, and dereferences to
+/// the wrapped driver so every driver method is still available. Hibernating
+/// on drop is off unless a driver is wrapped this way; [`Self::into_inner`]
+/// turns it back off by returning the driver without writing anything.
+///
+/// Doing I2C in `Drop` has limits worth knowing about:
+/// - Errors can't be returned, so a failed write is silently ignored. Call
+///   `hibernate()` directly if you need to know it worked.
+/// - The write happens wherever the wrapper is dropped, including while
+///   unwinding from a panic, when the bus may be in an unknown state.
+/// - Nothing is written if the wrapper is leaked with [`core::mem::forget`].
+///
+/// Only a reset wakes the controller from hibernation.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+/// use ft6336u_driver::{FT6336U, I2C_ADDR};
+///
+/// let expectations = [
+///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00]),
+///     // Written when `touch` goes out of scope
+///     Transaction::write(I2C_ADDR, vec![0xA5, 0x03]),
+/// ];
+/// let mut i2c = Mock::new(&expectations);
+///
+/// {
+///     let mut touch = FT6336U::new(i2c.clone()).hibernate_on_drop();
+///     assert!(!touch.has_touch().unwrap());
+/// }
+///
+/// i2c.done();
+/// ```
+pub struct HibernateOnDrop<I2C, const HISTORY: usize = 0>
+where
+    I2C: I2c,
+{
+    /// The wrapped driver, only taken by `into_inner`
+    touch: Option<FT6336U<I2C, HISTORY>>,
+}
+
+impl<I2C, const HISTORY: usize> HibernateOnDrop<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    /// Wrap a driver
+    pub(crate) fn new(touch: FT6336U<I2C, HISTORY>) -> Self {
+        Self { touch: Some(touch) }
+    }
+
+    /// Unwrap the driver without hibernating the controller
+    pub fn into_inner(mut self) -> FT6336U<I2C, HISTORY> {
+        self.touch.take().expect("driver is only taken once")
+    }
+}
+
+impl<I2C, const HISTORY: usize> Deref for HibernateOnDrop<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    type Target = FT6336U<I2C, HISTORY>;
+
+    fn deref(&self) -> &Self::Target {
+        self.touch.as_ref().expect("driver is only taken on unwrap")
+    }
+}
+
+impl<I2C, const HISTORY: usize> DerefMut for HibernateOnDrop<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.touch.as_mut().expect("driver is only taken on unwrap")
+    }
+}
+
+impl<I2C, const HISTORY: usize> Drop for HibernateOnDrop<I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    fn drop(&mut self) {
+        if let Some(touch) = self.touch.as_mut() {
+            // Nowhere to report a failure from here
+            let _ = touch.hibernate();
+        }
+    }
+}
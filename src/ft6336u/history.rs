@@ -0,0 +1,54 @@
+//! Fixed-capacity history of scanned frames.
+//!
+//! The driver keeps the last `HISTORY` frames it scanned so velocity and
+//! gesture code can look back without managing a buffer of its own. The
+//! capacity is a const generic defaulting to 0, so drivers that don't use the
+//! history pay nothing for it.
+
+use super::types::TouchData;
+
+/// The most recent `N` frames, oldest first
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct History<const N: usize> {
+    /// Stored frames; only the first `len` are meaningful
+    frames: [TouchData; N],
+    /// Number of stored frames
+    len: usize,
+}
+
+impl<const N: usize> History<N> {
+    /// Create an empty history
+    pub fn new() -> Self {
+        Self {
+            frames: [TouchData::default(); N],
+            len: 0,
+        }
+    }
+
+    /// Append a frame, dropping the oldest once full
+    ///
+    /// Frames are shifted down rather than kept in a ring so the history
+    /// stays a contiguous oldest-first slice. Capacities are small, so the
+    /// copy is cheap next to the I2C read that produced the frame.
+    pub fn push(&mut self, data: TouchData) {
+        if N == 0 {
+            return;
+        }
+        if self.len == N {
+            self.frames.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.frames[self.len] = data;
+        self.len += 1;
+    }
+
+    /// The stored frames, oldest first
+    pub fn as_slice(&self) -> &[TouchData] {
+        &self.frames[..self.len]
+    }
+
+    /// Forget every stored frame
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
@@ -0,0 +1,78 @@
+//! Touch presence as a digital input.
+//!
+//! [`TouchInput`] borrows the blocking driver and implements `embedded-hal`'s
+//! [`InputPin`], reading high while the panel is touched, so the controller
+//! can be plugged into code that polls buttons or other digital inputs.
+//!
+//! This module is only available without the `async` feature, since
+//! `InputPin` is a blocking trait.
+
+use embedded_hal::digital::{ErrorType, InputPin};
+use embedded_hal::i2c::I2c;
+
+use super::driver::FT6336U;
+use super::error::Error;
+
+/// The panel's touch state as an [`InputPin`]
+///
+/// Created by the driver's `as_input_pin()` method. Each read is a
+/// single-byte `TD_STATUS` read through `has_touch()`: the pin is high while
+/// at least one touch is reported and low otherwise. The cached touch data is
+/// not updated.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_hal::digital::InputPin;
+/// use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+/// use ft6336u_driver::{FT6336U, I2C_ADDR};
+///
+/// // Works with anything that polls a digital input
+/// fn pressed<P: InputPin>(pin: &mut P) -> bool {
+///     pin.is_high().unwrap_or(false)
+/// }
+///
+/// let expectations = [
+///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x00]),
+///     Transaction::write_read(I2C_ADDR, vec![0x02], vec![0x01]),
+/// ];
+/// let mut i2c = Mock::new(&expectations);
+/// let mut touch = FT6336U::new(i2c.clone());
+///
+/// let mut pin = touch.as_input_pin();
+/// assert!(!pressed(&mut pin));
+/// assert!(pressed(&mut pin));
+///
+/// i2c.done();
+/// ```
+pub struct TouchInput<'a, I2C, const HISTORY: usize = 0> {
+    /// The driver used to read the touch state
+    touch: &'a mut FT6336U<I2C, HISTORY>,
+}
+
+impl<'a, I2C, const HISTORY: usize> TouchInput<'a, I2C, HISTORY> {
+    /// Wrap a driver
+    pub(crate) fn new(touch: &'a mut FT6336U<I2C, HISTORY>) -> Self {
+        Self { touch }
+    }
+}
+
+impl<I2C, const HISTORY: usize> ErrorType for TouchInput<'_, I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    type Error = Error<I2C::Error>;
+}
+
+impl<I2C, const HISTORY: usize> InputPin for TouchInput<'_, I2C, HISTORY>
+where
+    I2C: I2c,
+{
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.touch.has_touch()
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.touch.has_touch()?)
+    }
+}
@@ -0,0 +1,70 @@
+//! Integer math helpers shared by the geometry and gesture code.
+//!
+//! Everything here avoids floating point so the crate stays usable on
+//! targets without an FPU or `libm`.
+
+use super::types::Coord;
+
+/// Exact squared distance between two positions
+///
+/// Differences of up to 65535 on each axis are possible, so the sum of
+/// squares can overflow `u32`; it always fits `u64`.
+pub(crate) fn squared_distance(a: (Coord, Coord), b: (Coord, Coord)) -> u64 {
+    let dx = a.0.abs_diff(b.0) as u64;
+    let dy = a.1.abs_diff(b.1) as u64;
+    dx * dx + dy * dy
+}
+
+/// Integer square root, rounded down
+///
+/// The digit-by-digit method in base 4: each step tries the next bit of the
+/// root and keeps it if the remainder allows, so it takes at most 16
+/// iterations using only shifts, additions and comparisons. Every distance
+/// in the crate goes through here rather than approximating its own root.
+pub(crate) fn isqrt(n: u32) -> u16 {
+    let mut rem = n;
+    let mut root = 0u32;
+    let mut bit = 1u32 << 30;
+    while bit > rem {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root as u16
+}
+
+/// Angle of the vector `(x, y)` in whole degrees, `0..360`
+///
+/// Measured counter-clockwise from the positive X axis, like `atan2(y, x)`.
+/// Within one octant the arctangent of the ratio `r` of the smaller to the
+/// larger component is approximated as `45r + 15.64r(1 - r)` degrees, which
+/// stays within about 0.3 degrees of the true value, so the rounded result is
+/// off by at most one degree. `(0, 0)` yields 0.
+#[cfg(feature = "gestures")]
+pub(crate) fn atan2_deg(y: i32, x: i32) -> u16 {
+    let (ax, ay) = (x.unsigned_abs(), y.unsigned_abs());
+    let (lo, hi) = if ay <= ax { (ay, ax) } else { (ax, ay) };
+    if hi == 0 {
+        return 0;
+    }
+
+    // Ratio in 1/1024ths, then the octant angle in hundredths of a degree
+    let r = (lo as u64 * 1024 / hi as u64) as u32;
+    let octant = (4500 * r + 1564 * r * (1024 - r) / 1024) / 1024;
+
+    let mut angle = if ay <= ax { octant } else { 9000 - octant };
+    if x < 0 {
+        angle = 18000 - angle;
+    }
+    if y < 0 {
+        angle = 36000 - angle;
+    }
+    ((angle + 50) / 100 % 360) as u16
+}
@@ -90,19 +90,54 @@
 //! On the CoreSE-S3 board, the FT6336U is connected via the AW9523B GPIO expander
 //! which manages the touch controller's reset and interrupt pins.
 
+mod config;
 mod constants;
+mod controller;
+mod diagnostics;
 #[cfg(not(feature = "async"))]
 mod driver;
 #[cfg(feature = "async")]
 mod driver_async;
 mod error;
+mod event;
+mod frame;
+#[cfg(feature = "gestures")]
+pub mod gesture;
+#[cfg(not(feature = "async"))]
+mod hibernate;
+mod history;
+#[cfg(not(feature = "async"))]
+mod input;
+mod math;
+mod parse;
+mod poll;
+mod state;
+#[cfg(feature = "async")]
+mod stream;
+mod trace;
 mod types;
+#[cfg(feature = "ufmt")]
+mod ufmt;
 
 // Re-export public API
+pub use config::Config;
+#[cfg(feature = "gestures")]
+pub use config::GestureParams;
 pub use constants::*;
+pub use controller::TouchController;
+pub use diagnostics::RegisterDump;
 #[cfg(not(feature = "async"))]
 pub use driver::FT6336U;
 #[cfg(feature = "async")]
 pub use driver_async::FT6336U;
-pub use error::Error;
+pub use error::{Error, InvalidEnumValue};
+pub use event::{Event, EventIter};
+#[cfg(not(feature = "async"))]
+pub use hibernate::HibernateOnDrop;
+#[cfg(not(feature = "async"))]
+pub use input::TouchInput;
+pub use poll::PollScheduler;
+#[cfg(feature = "async")]
+pub use stream::TouchFrames;
+pub use trace::{TraceEvent, TraceFn};
 pub use types::*;
@@ -0,0 +1,250 @@
+//! Decoding of raw register blocks read from the FT6336U.
+//!
+//! Both the blocking and async drivers read the same registers, so the
+//! bit-level layout lives here once instead of in each driver.
+
+use super::types::{
+    ByteOrder, Coord, DeviceInfo, GestureMode, TouchData, TouchStatus, TrackingMode,
+};
+
+/// Length of the block from `TD_STATUS` through touch point 1's registers
+pub(crate) const TOUCH1_BLOCK_LEN: usize = 7;
+/// Length of touch point 2's register block (`P2_XH` through `P2_MISC`)
+pub(crate) const POINT_BLOCK_LEN: usize = 6;
+/// Length of the block from `GEST_ID` through touch point 2's registers
+#[cfg(feature = "gestures")]
+pub(crate) const GESTURE_BLOCK_LEN: usize = 1 + TOUCH1_BLOCK_LEN + POINT_BLOCK_LEN;
+/// Length of the system information block (`LIB_VER_H` through `FIRMID`)
+pub(crate) const SYSTEM_INFO_LEN: usize = 6;
+/// Length of the panel resolution block (`MAX_X_H` through `MAX_Y_L`)
+pub(crate) const RESOLUTION_LEN: usize = 4;
+
+/// One touch point's registers, decoded
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RawPoint {
+    /// Event flag (bits 7:6 of `Pn_XH`)
+    pub event: u8,
+    /// Touch ID (bits 7:4 of `Pn_YH`)
+    pub id: u8,
+    /// 12-bit X coordinate
+    pub x: u16,
+    /// 12-bit Y coordinate
+    pub y: u16,
+    /// Touch weight (`Pn_WEIGHT`)
+    pub weight: u8,
+}
+
+/// Split a coordinate register pair into its high and low bytes
+fn coord_bytes(pair: &[u8], order: ByteOrder) -> (u8, u8) {
+    match order {
+        ByteOrder::BigEndian => (pair[0], pair[1]),
+        ByteOrder::LittleEndian => (pair[1], pair[0]),
+    }
+}
+
+/// Decode a 12-bit coordinate from its register pair, in register order
+///
+/// Bits above the coordinate in the high byte (event flag or touch ID) are
+/// masked off.
+pub(crate) fn parse_coord(pair: &[u8], order: ByteOrder) -> u16 {
+    let (high, low) = coord_bytes(pair, order);
+    (((high & 0x0F) as u16) << 8) | (low as u16)
+}
+
+impl RawPoint {
+    /// Decode a point from its six registers, starting at `Pn_XH`
+    pub fn parse(buf: &[u8], order: ByteOrder) -> Self {
+        let (x_high, _) = coord_bytes(&buf[0..2], order);
+        let (y_high, _) = coord_bytes(&buf[2..4], order);
+        Self {
+            event: x_high >> 6,
+            id: y_high >> 4,
+            x: parse_coord(&buf[0..2], order),
+            y: parse_coord(&buf[2..4], order),
+            weight: buf[4],
+        }
+    }
+}
+
+/// The touch status and point registers, decoded
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TouchBlock {
+    /// Number of touches reported by `TD_STATUS`, capped at 2
+    pub count: u8,
+    /// Point registers in report order; only the first `count` are meaningful
+    pub points: [RawPoint; 2],
+}
+
+impl TouchBlock {
+    /// Decode a block starting at `TD_STATUS`
+    ///
+    /// Touch point 2 is only decoded if two touches are reported and `buf`
+    /// extends through its registers.
+    pub fn parse(buf: &[u8], order: ByteOrder) -> Self {
+        let mut block = Self {
+            count: (buf[0] & 0x0F).min(2),
+            ..Default::default()
+        };
+        block.points[0] = RawPoint::parse(&buf[1..TOUCH1_BLOCK_LEN], order);
+        if block.count == 2 && buf.len() >= TOUCH1_BLOCK_LEN + POINT_BLOCK_LEN {
+            block.points[1] = RawPoint::parse(&buf[TOUCH1_BLOCK_LEN..], order);
+        }
+        block
+    }
+
+    /// Drop reported points that don't satisfy `keep`, preserving report order
+    pub fn retain(&mut self, mut keep: impl FnMut(&RawPoint) -> bool) {
+        let mut count = 0;
+        for i in 0..self.count as usize {
+            if keep(&self.points[i]) {
+                self.points[count] = self.points[i];
+                count += 1;
+            }
+        }
+        self.count = count as u8;
+    }
+
+    /// Whether two points are reported with the same touch ID
+    ///
+    /// Some firmware reports ID 0 for both points while two fingers are down.
+    pub fn ids_collide(&self) -> bool {
+        self.count == 2 && self.points[0].id == self.points[1].id
+    }
+
+    /// Update cached touch data from this block
+    ///
+    /// Points are stored by hardware touch ID, or by report order with
+    /// [`TrackingMode::Positional`]. A point that was previously released
+    /// becomes `Touch`, and one that was already down becomes `Stream`. If
+    /// both points report the same ID they are stored by report order either
+    /// way, so neither finger is lost.
+    pub fn apply(&self, data: &mut TouchData, tracking: TrackingMode) {
+        data.touch_count = self.count;
+
+        if self.count == 0 {
+            for point in data.points.iter_mut() {
+                point.status = TouchStatus::Release;
+            }
+            return;
+        }
+
+        let positional = tracking == TrackingMode::Positional || self.ids_collide();
+        for (index, raw) in self.points[..self.count as usize].iter().enumerate() {
+            let id = if positional { index } else { raw.id as usize };
+            if id >= 2 {
+                continue;
+            }
+
+            let point = &mut data.points[id];
+            point.status = match point.status {
+                TouchStatus::Release => TouchStatus::Touch,
+                _ => TouchStatus::Stream,
+            };
+            point.x = raw.x as Coord;
+            point.y = raw.y as Coord;
+            point.raw_event = raw.event;
+            point.weight = raw.weight;
+            point.id = raw.id;
+
+            // With a single touch, the other slot has been lifted
+            if self.count == 1 {
+                data.points[id ^ 1].status = TouchStatus::Release;
+            }
+        }
+    }
+}
+
+/// Combines repeated reads of the touch block into one confirmed block
+///
+/// A point from the first read is kept only if every later read reports it
+/// too, matched by touch ID (or by position when IDs collide or positional
+/// tracking is on), and its coordinates are averaged across the reads.
+pub(crate) struct Confirmation {
+    /// The first read, whose points are being confirmed
+    block: TouchBlock,
+    /// How points of later reads are matched to those of `block`
+    tracking: TrackingMode,
+    /// Coordinate sums for each point of `block`
+    sums: [(u32, u32); 2],
+    /// Whether each point of `block` has been seen in every read so far
+    present: [bool; 2],
+    /// Number of reads folded in
+    samples: u32,
+}
+
+impl Confirmation {
+    /// Start from the first read
+    pub fn new(block: TouchBlock, tracking: TrackingMode) -> Self {
+        let mut sums = [(0, 0); 2];
+        for (sum, point) in sums.iter_mut().zip(block.points.iter()) {
+            *sum = (point.x as u32, point.y as u32);
+        }
+        Self {
+            block,
+            tracking,
+            sums,
+            present: [true; 2],
+            samples: 1,
+        }
+    }
+
+    /// Fold in another read
+    pub fn add(&mut self, sample: &TouchBlock) {
+        let positional = self.tracking == TrackingMode::Positional
+            || self.block.ids_collide()
+            || sample.ids_collide();
+        let reported = &sample.points[..sample.count as usize];
+        for i in 0..self.block.count as usize {
+            let id = self.block.points[i].id;
+            let found = if positional {
+                reported.get(i)
+            } else {
+                reported.iter().find(|point| point.id == id)
+            };
+            match found {
+                Some(point) => {
+                    self.sums[i].0 += point.x as u32;
+                    self.sums[i].1 += point.y as u32;
+                }
+                None => self.present[i] = false,
+            }
+        }
+        self.samples += 1;
+    }
+
+    /// The confirmed points with averaged coordinates
+    pub fn finish(mut self) -> TouchBlock {
+        for (point, sum) in self.block.points.iter_mut().zip(self.sums.iter()) {
+            point.x = (sum.0 / self.samples) as u16;
+            point.y = (sum.1 / self.samples) as u16;
+        }
+        let mut present = self.present.iter();
+        self.block.retain(|_| *present.next().unwrap_or(&false));
+        self.block
+    }
+}
+
+/// Decode the system information block starting at `LIB_VER_H`
+///
+/// Returns `None` if `G_MODE` holds an unknown value.
+pub(crate) fn parse_device_info(buf: &[u8; SYSTEM_INFO_LEN]) -> Option<DeviceInfo> {
+    Some(DeviceInfo {
+        library_version: (((buf[0] & 0x0F) as u16) << 8) | (buf[1] as u16),
+        chip_id: buf[2],
+        gesture_mode: GestureMode::from_register(buf[3])?,
+        power_mode: buf[4],
+        firmware_id: buf[5],
+    })
+}
+
+/// Decode the panel resolution block starting at `MAX_X_H`
+///
+/// Firmware without these registers typically reads back all zeros or all
+/// ones, so zero sizes and sizes beyond the 12-bit coordinate range are
+/// treated as absent.
+pub(crate) fn parse_resolution(buf: &[u8; RESOLUTION_LEN]) -> Option<(u16, u16)> {
+    let width = u16::from_be_bytes([buf[0], buf[1]]);
+    let height = u16::from_be_bytes([buf[2], buf[3]]);
+    let valid = |size: u16| (1..=4096).contains(&size);
+    (valid(width) && valid(height)).then_some((width, height))
+}
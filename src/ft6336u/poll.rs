@@ -0,0 +1,106 @@
+//! Power-aware polling cadence.
+//!
+//! [`PollScheduler`] decides how long to wait before the next scan: a short
+//! interval while the panel is being touched, and a longer one once it has
+//! been idle for a while. The driver's `poll()` uses it to run the whole
+//! polling loop in one call, but it can also drive a hand-written loop.
+
+use super::types::TouchData;
+
+/// Scan interval that slows down while the panel is idle
+///
+/// After every frame is fed to [`Self::update`], [`Self::interval_ms`] returns
+/// the active interval, unless the last `idle_after` frames had no active
+/// touches, in which case it returns the idle interval. The first touch
+/// switches straight back to the active interval.
+///
+/// The default scans every 10ms while touched (about the controller's
+/// maximum report rate), and every 100ms after 10 empty frames.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{PollScheduler, TouchData, TouchPoint, TouchStatus};
+///
+/// let mut scheduler = PollScheduler::new(10, 100).with_idle_after(2);
+/// let idle = TouchData::default();
+/// let mut touched = TouchData::default();
+/// touched.touch_count = 1;
+/// touched.points[0] = TouchPoint {
+///     status: TouchStatus::Touch,
+///     ..Default::default()
+/// };
+///
+/// scheduler.update(&idle);
+/// assert_eq!(scheduler.interval_ms(), 10);
+/// scheduler.update(&idle);
+/// assert_eq!(scheduler.interval_ms(), 100);
+///
+/// scheduler.update(&touched);
+/// assert_eq!(scheduler.interval_ms(), 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollScheduler {
+    /// Interval while the panel is touched, in milliseconds
+    active_ms: u32,
+    /// Interval once the panel is idle, in milliseconds
+    idle_ms: u32,
+    /// Consecutive empty frames before switching to the idle interval
+    idle_after: u8,
+    /// Consecutive empty frames seen so far
+    empty_frames: u8,
+}
+
+impl PollScheduler {
+    /// Create a scheduler with the given active and idle intervals
+    ///
+    /// # Arguments
+    /// * `active_ms` - Interval while the panel is touched, in milliseconds
+    /// * `idle_ms` - Interval once the panel is idle, in milliseconds
+    pub const fn new(active_ms: u32, idle_ms: u32) -> Self {
+        Self {
+            active_ms,
+            idle_ms,
+            idle_after: 10,
+            empty_frames: 0,
+        }
+    }
+
+    /// Set how many consecutive empty frames switch to the idle interval
+    ///
+    /// Staying fast for a few frames after a release keeps a quick second
+    /// tap responsive.
+    pub fn with_idle_after(mut self, frames: u8) -> Self {
+        self.idle_after = frames;
+        self
+    }
+
+    /// Record a scanned frame
+    pub fn update(&mut self, data: &TouchData) {
+        if data.is_empty() {
+            self.empty_frames = self.empty_frames.saturating_add(1);
+        } else {
+            self.empty_frames = 0;
+        }
+    }
+
+    /// Time to wait before the next scan, in milliseconds
+    pub fn interval_ms(&self) -> u32 {
+        if self.empty_frames >= self.idle_after {
+            self.idle_ms
+        } else {
+            self.active_ms
+        }
+    }
+
+    /// Forget past frames, returning to the active interval
+    pub fn reset(&mut self) {
+        self.empty_frames = 0;
+    }
+}
+
+impl Default for PollScheduler {
+    fn default() -> Self {
+        Self::new(10, 100)
+    }
+}
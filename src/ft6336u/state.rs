@@ -0,0 +1,223 @@
+//! Touch tracking state shared by the blocking and async drivers.
+//!
+//! Everything that turns decoded register blocks into [`TouchData`] frames
+//! lives here, so both drivers apply identical processing after their I2C
+//! reads.
+
+use super::constants::MAX_TOUCH_POINTS;
+use super::parse::TouchBlock;
+use super::types::*;
+
+/// Linear map from the sensor's coordinate range onto a display
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scaling {
+    /// Largest raw X coordinate the sensor reports
+    pub src_max_x: u16,
+    /// Largest raw Y coordinate the sensor reports
+    pub src_max_y: u16,
+    /// Display width in pixels
+    pub dst_w: u16,
+    /// Display height in pixels
+    pub dst_h: u16,
+}
+
+/// Largest display size whose pixels all fit a signed coordinate
+#[cfg(feature = "i16-coords")]
+const MAX_SIGNED_EXTENT: u16 = i16::MAX as u16 + 1;
+
+impl Scaling {
+    /// Scaling onto a `dst_w` x `dst_h` display
+    ///
+    /// With `i16-coords`, display sizes are clamped to 32768 so every mapped
+    /// coordinate stays non-negative.
+    pub fn new(src_max_x: u16, src_max_y: u16, dst_w: u16, dst_h: u16) -> Self {
+        #[cfg(feature = "i16-coords")]
+        let (dst_w, dst_h) = (dst_w.min(MAX_SIGNED_EXTENT), dst_h.min(MAX_SIGNED_EXTENT));
+        Self {
+            src_max_x,
+            src_max_y,
+            dst_w,
+            dst_h,
+        }
+    }
+
+    /// Map one raw coordinate onto `0..dst`
+    fn map(raw: u16, src_max: u16, dst: u16) -> u16 {
+        let raw = raw.min(src_max) as u32;
+        (raw * dst as u32 / (src_max as u32 + 1)) as u16
+    }
+
+    /// Map a raw `(x, y)` pair onto the display
+    pub fn apply(&self, x: u16, y: u16) -> (u16, u16) {
+        (
+            Self::map(x, self.src_max_x, self.dst_w),
+            Self::map(y, self.src_max_y, self.dst_h),
+        )
+    }
+}
+
+/// Cached touch data and the settings that control how scans are processed
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ScanState {
+    /// Cached touch point data from last scan
+    pub touch_data: TouchData,
+    /// Timestamp of the latest `Touch` for each slot, used by `scan_at`
+    pub press_start: [u32; MAX_TOUCH_POINTS],
+    /// Minimum weight for a point to count as touching (0 disables filtering)
+    pub min_weight: u8,
+    /// Coordinate scaling applied to every point, if set
+    pub scaling: Option<Scaling>,
+    /// Width of the border, in reported coordinates, where touches are ignored
+    pub edge_margin: u16,
+    /// Largest movement, in reported coordinates, that keeps the old position
+    pub move_deadband: u16,
+    /// Number of touch block reads each scan combines (0 and 1 read once)
+    pub confirm_samples: u8,
+    /// Number of scans where both points reported the same touch ID
+    pub id_collisions: u32,
+    /// Consecutive scans a point may be missing before it is released
+    pub release_debounce: u8,
+    /// How reported points are assigned to slots
+    pub tracking: TrackingMode,
+    /// Consecutive scans each slot has been missing while held down
+    missing: [u8; MAX_TOUCH_POINTS],
+    /// Whether frames are collapsed to a single logical point
+    single_touch: bool,
+    /// Slot reported as the single logical point, while single-touch is on
+    primary: Option<usize>,
+}
+
+impl ScanState {
+    /// Filter and scale a freshly read block and fold it into the cached touch data
+    ///
+    /// Weight filtering runs on the raw block and edge rejection on the scaled
+    /// coordinates, before points are assigned to slots.
+    pub fn process(&mut self, mut block: TouchBlock) -> TouchData {
+        // The primary's release was reported by the previous frame
+        if let Some(slot) = self.primary {
+            if !self.touch_data.points[slot].is_active() {
+                self.primary = None;
+            }
+        }
+
+        self.filter_weight(&mut block);
+        if block.ids_collide() {
+            self.id_collisions = self.id_collisions.wrapping_add(1);
+        }
+        self.map_to_display(&mut block);
+        let prev = self.touch_data;
+        block.apply(&mut self.touch_data, self.tracking);
+        if self.move_deadband > 0 {
+            self.apply_deadband(&prev);
+        }
+        self.debounce_releases(&prev);
+        if self.single_touch && self.primary.is_none() {
+            let mut points = self.touch_data.points.iter();
+            self.primary = points.position(|point| point.status == TouchStatus::Touch);
+        }
+        self.frame()
+    }
+
+    /// Drop points lighter than the minimum weight
+    pub fn filter_weight(&self, block: &mut TouchBlock) {
+        let min_weight = self.min_weight;
+        block.retain(|point| point.weight >= min_weight);
+    }
+
+    /// Scale points onto the display and drop those inside the edge margin
+    pub fn map_to_display(&self, block: &mut TouchBlock) {
+        if let Some(scaling) = self.scaling {
+            for point in block.points.iter_mut() {
+                (point.x, point.y) = scaling.apply(point.x, point.y);
+            }
+        }
+        // Without a display size there is no reliable far edge to measure
+        // the margin from, since panels rarely span the whole raw range
+        if let Some(scaling) = self.scaling.filter(|_| self.edge_margin > 0) {
+            let margin = self.edge_margin;
+            block.retain(|point| {
+                point.x >= margin
+                    && point.y >= margin
+                    && point.x < scaling.dst_w.saturating_sub(margin)
+                    && point.y < scaling.dst_h.saturating_sub(margin)
+            });
+        }
+    }
+
+    /// Turn single-touch emulation on or off, forgetting the current primary
+    pub fn set_single_touch(&mut self, enabled: bool) {
+        self.single_touch = enabled;
+        self.primary = None;
+    }
+
+    /// The cached touch data as reported to the application
+    ///
+    /// With single-touch emulation on, only the primary point is kept, moved
+    /// to slot 0.
+    pub fn frame(&self) -> TouchData {
+        if !self.single_touch {
+            return self.touch_data;
+        }
+        let mut frame = TouchData::default();
+        if let Some(slot) = self.primary {
+            frame.points[0] = self.touch_data.points[slot];
+            frame.touch_count = frame.points[0].is_active() as u8;
+        }
+        frame
+    }
+
+    /// Hold each continuing point at its last reported position until it
+    /// moves further than the deadband
+    fn apply_deadband(&mut self, prev: &TouchData) {
+        for (point, old) in self.touch_data.points.iter_mut().zip(prev.points.iter()) {
+            if point.status == TouchStatus::Stream
+                && old.is_active()
+                && !point.moved_more_than(old, self.move_deadband)
+            {
+                (point.x, point.y) = (old.x, old.y);
+            }
+        }
+    }
+
+    /// Keep reporting points that just vanished until the debounce runs out
+    ///
+    /// A held point keeps its last position and is reported as `Stream`.
+    fn debounce_releases(&mut self, prev: &TouchData) {
+        let slots = self.touch_data.points.iter_mut().zip(prev.points.iter());
+        for ((point, old), missing) in slots.zip(self.missing.iter_mut()) {
+            if !point.is_active() && old.is_active() && *missing < self.release_debounce {
+                *missing += 1;
+                point.status = TouchStatus::Stream;
+                self.touch_data.touch_count += 1;
+            } else {
+                *missing = 0;
+            }
+        }
+    }
+
+    /// Update each point's `pressed_ms` from the time its press started
+    pub fn track_pressed_time(&mut self, now_ms: u32) -> TouchData {
+        let points = self.touch_data.points.iter_mut();
+        for (point, start) in points.zip(self.press_start.iter_mut()) {
+            match point.status {
+                TouchStatus::Touch => {
+                    *start = now_ms;
+                    point.pressed_ms = 0;
+                }
+                TouchStatus::Stream => point.pressed_ms = now_ms.wrapping_sub(*start),
+                TouchStatus::Release => {}
+            }
+        }
+        self.frame()
+    }
+
+    /// Mark every cached point as released
+    pub fn release_all(&mut self) -> TouchData {
+        self.missing = [0; MAX_TOUCH_POINTS];
+        self.touch_data.touch_count = 0;
+        for point in self.touch_data.points.iter_mut() {
+            point.status = TouchStatus::Release;
+        }
+        self.frame()
+    }
+}
@@ -0,0 +1,59 @@
+//! Interrupt-driven stream of touch frames for the async driver.
+//!
+//! [`TouchFrames`] borrows the driver and its INT pin and hands out one
+//! frame per interrupt, for `while let Some(frame) = frames.next().await`
+//! loops. The crate doesn't depend on `futures`, so it isn't a `Stream`
+//! impl, but [`TouchFrames::next`] follows the same shape and is easy to
+//! adapt with `futures::stream::unfold`.
+
+use embedded_hal_async::digital::Wait;
+use embedded_hal_async::i2c::I2c;
+
+use super::driver_async::FT6336U;
+use super::error::Error;
+use super::types::TouchData;
+
+/// A never-ending sequence of touch frames, one per interrupt
+///
+/// Created by the driver's `frames()` method. Each call to [`Self::next`]
+/// awaits the INT pin going low, scans, and yields the frame, exactly like
+/// the driver's `wait_for_touch()`.
+///
+/// # Cancellation safety
+///
+/// `next()` is cancel-safe while it is waiting on the pin, which is where it
+/// spends nearly all of its time, so it can be raced against other futures
+/// with `select`. Dropping it during the scan that follows abandons that
+/// frame: the cached touch data, history and sequence numbers are only
+/// updated once the scan completes, so the next call picks up from the
+/// previous frame as if the interrupt had been missed.
+pub struct TouchFrames<'a, I2C, P, const HISTORY: usize = 0>
+where
+    I2C: I2c,
+    P: Wait,
+{
+    /// The driver that scans each frame
+    touch: &'a mut FT6336U<I2C, HISTORY>,
+    /// The controller's active-low interrupt pin
+    int: &'a mut P,
+}
+
+impl<'a, I2C, P, const HISTORY: usize> TouchFrames<'a, I2C, P, HISTORY>
+where
+    I2C: I2c,
+    P: Wait,
+{
+    /// Borrow a driver and its interrupt pin
+    pub(crate) fn new(touch: &'a mut FT6336U<I2C, HISTORY>, int: &'a mut P) -> Self {
+        Self { touch, int }
+    }
+
+    /// Wait for the next interrupt and yield the scanned frame
+    ///
+    /// # Returns
+    /// Always `Some`: the frame, or the error from waiting on the pin or
+    /// scanning. The stream carries on after an error.
+    pub async fn next(&mut self) -> Option<Result<TouchData, Error<I2C::Error>>> {
+        Some(self.touch.wait_for_touch(self.int).await)
+    }
+}
@@ -0,0 +1,22 @@
+//! Optional tracing of the driver's register transfers.
+//!
+//! A trace hook installed with the driver's `set_trace()` is called after
+//! every successful register read or write, which makes it possible to see
+//! exactly what the driver puts on the bus without a logic analyzer.
+
+/// A completed register transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent<'a> {
+    /// First register address of the transfer
+    pub addr: u8,
+    /// `true` for register writes, `false` for reads
+    pub is_write: bool,
+    /// Bytes written to or read from the registers starting at `addr`
+    pub bytes: &'a [u8],
+}
+
+/// Hook called with each [`TraceEvent`]
+///
+/// A plain function pointer keeps the driver free of generics and costs a
+/// single branch per transfer when no hook is set.
+pub type TraceFn = fn(TraceEvent<'_>);
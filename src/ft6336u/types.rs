@@ -3,6 +3,13 @@
 //! This module contains enums and structs representing the various
 //! states and data structures used by the touch controller.
 
+use super::constants::{
+    CHIP_ID, DEFAULT_FILTER_COE, EVENT_CONTACT, EVENT_LIFT_UP, EVENT_NO_EVENT, EVENT_PRESS_DOWN,
+    MAX_TOUCH_POINTS,
+};
+use super::error::InvalidEnumValue;
+use super::math::{isqrt, squared_distance};
+
 /// Device operating mode
 ///
 /// The FT6336U can operate in different modes for normal operation or factory testing.
@@ -43,6 +50,15 @@ impl DeviceMode {
     }
 }
 
+impl TryFrom<u8> for DeviceMode {
+    type Error = InvalidEnumValue;
+
+    /// Same as [`DeviceMode::from_register`], with the rejected value as the error
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Self::from_register(val).ok_or(InvalidEnumValue(val))
+    }
+}
+
 /// Control mode for power management
 ///
 /// Controls whether the device stays in active mode or switches to lower-power monitor mode.
@@ -76,10 +92,32 @@ impl CtrlMode {
     }
 }
 
+impl TryFrom<u8> for CtrlMode {
+    type Error = InvalidEnumValue;
+
+    /// Same as [`CtrlMode::from_register`], with the rejected value as the error
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Self::from_register(val).ok_or(InvalidEnumValue(val))
+    }
+}
+
 /// Gesture mode (interrupt trigger configuration)
 ///
 /// Configures whether the device generates interrupts on touch events or requires polling.
 ///
+/// The `G_MODE` register (`0xA4`) uses only bit 0; the remaining bits are
+/// reserved. That bit is what selects the INT pin behavior:
+///
+/// | Bit 0 | Mode      | INT pin                                                |
+/// |-------|-----------|--------------------------------------------------------|
+/// | 0     | `Polling` | Held low for as long as a touch is present             |
+/// | 1     | `Trigger` | Pulsed low once for each new touch report              |
+///
+/// So "interrupt while touched" is [`GestureMode::Polling`] (level-style, useful
+/// as a simple touch-present signal) and "interrupt on each update" is
+/// [`GestureMode::Trigger`] (edge-style). There is no separate bit to choose
+/// between the two behaviors.
+///
 /// # Examples
 ///
 /// ```rust
@@ -92,9 +130,9 @@ impl CtrlMode {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum GestureMode {
-    /// Polling mode - no interrupts
+    /// Polling mode - INT held low while touched
     Polling = 0,
-    /// Trigger mode - generate interrupts on touch events
+    /// Trigger mode - INT pulsed on each touch report
     Trigger = 1,
 }
 
@@ -109,6 +147,277 @@ impl GestureMode {
     }
 }
 
+impl TryFrom<u8> for GestureMode {
+    type Error = InvalidEnumValue;
+
+    /// Same as [`GestureMode::from_register`], with the rejected value as the error
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Self::from_register(val).ok_or(InvalidEnumValue(val))
+    }
+}
+
+/// Strength of the controller's coordinate filter
+///
+/// A friendlier view of the filter coefficient register (`ID_G_THDIFF`).
+/// Stronger filtering smooths jitter at the cost of some lag. `Medium` writes
+/// the power-on default, [`DEFAULT_FILTER_COE`]; the register description
+/// gives no other recommended values, so `Low` and `High` are spaced evenly
+/// around it and `Off` writes 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{FilterStrength, DEFAULT_FILTER_COE};
+///
+/// assert_eq!(FilterStrength::Medium.to_register(), DEFAULT_FILTER_COE);
+/// // Read-back values are classified by the nearest strength
+/// assert_eq!(FilterStrength::from_register(0x90), FilterStrength::Medium);
+/// assert_eq!(FilterStrength::from_register(0x10), FilterStrength::Off);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FilterStrength {
+    /// No filtering
+    Off = 0x00,
+    /// Light filtering
+    Low = 0x50,
+    /// The power-on default
+    Medium = DEFAULT_FILTER_COE,
+    /// Heavy filtering
+    High = 0xF0,
+}
+
+impl FilterStrength {
+    /// Every strength, weakest first
+    const ALL: [Self; 4] = [Self::Off, Self::Low, Self::Medium, Self::High];
+
+    /// Classify a raw coefficient as the nearest strength
+    ///
+    /// Values halfway between two strengths round to the weaker one.
+    pub fn from_register(val: u8) -> Self {
+        let mut nearest = Self::Off;
+        for strength in Self::ALL {
+            if strength.to_register().abs_diff(val) < nearest.to_register().abs_diff(val) {
+                nearest = strength;
+            }
+        }
+        nearest
+    }
+
+    /// Convert to register value
+    pub fn to_register(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Order of the two registers that make up each touch coordinate
+///
+/// The datasheet puts the high register first: `Pn_XH` holds the event flag
+/// and the top four bits of X, and `Pn_XL` the low eight bits. Some clone
+/// firmware swaps the pair, sending the low byte first. With
+/// [`ByteOrder::LittleEndian`] the second register of each pair is treated as
+/// the high one, including its event flag or touch ID bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::ByteOrder;
+///
+/// assert_eq!(ByteOrder::default(), ByteOrder::BigEndian);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// High register first, as in the datasheet
+    #[default]
+    BigEndian,
+    /// Low register first, as sent by some clone firmware
+    LittleEndian,
+}
+
+/// How reported touch points are assigned to the two slots of [`TouchData`]
+///
+/// Each point carries a touch ID, but firmware is not consistent about it:
+/// some versions report the same ID for both fingers, and some renumber a
+/// finger when the other one lifts. [`TrackingMode::HardwareId`] trusts the
+/// IDs, so a finger stays in the same slot from press to release.
+/// [`TrackingMode::Positional`] ignores them and fills the slots in report
+/// order, which cannot be confused by bad IDs but loses finger identity
+/// across frames: when the first finger lifts, the second one moves to
+/// slot 0.
+///
+/// Even with `HardwareId`, points that report the same ID are stored by
+/// report order so neither finger is lost.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::TrackingMode;
+///
+/// assert_eq!(TrackingMode::default(), TrackingMode::HardwareId);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrackingMode {
+    /// Store each point in the slot given by its touch ID
+    #[default]
+    HardwareId,
+    /// Store points in report order, ignoring touch IDs
+    Positional,
+}
+
+/// Gesture reported by the gesture ID register
+///
+/// The FT6336U reports the last recognized gesture in the `GEST_ID` register
+/// (`0x01`). The codes, from the register map in the FT6336U datasheet:
+///
+/// | Code   | Gesture                 |
+/// |--------|-------------------------|
+/// | `0x10` | [`Gesture::MoveUp`]     |
+/// | `0x14` | [`Gesture::MoveRight`]  |
+/// | `0x18` | [`Gesture::MoveDown`]   |
+/// | `0x1C` | [`Gesture::MoveLeft`]   |
+/// | `0x48` | [`Gesture::ZoomIn`]     |
+/// | `0x49` | [`Gesture::ZoomOut`]    |
+/// | `0x00` | [`Gesture::None`]       |
+///
+/// The zoom codes don't follow the step of 4 between the move codes, so they
+/// are easy to misread as unknown values. Any other code is rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::Gesture;
+///
+/// let documented = [
+///     (0x10, Gesture::MoveUp),
+///     (0x14, Gesture::MoveRight),
+///     (0x18, Gesture::MoveDown),
+///     (0x1C, Gesture::MoveLeft),
+///     (0x48, Gesture::ZoomIn),
+///     (0x49, Gesture::ZoomOut),
+///     (0x00, Gesture::None),
+/// ];
+/// for (code, gesture) in documented {
+///     assert_eq!(Gesture::from_register(code), Some(gesture));
+///     assert_eq!(gesture as u8, code);
+/// }
+///
+/// // Neighbours of the documented codes are not gestures
+/// assert!(Gesture::from_register(0x20).is_none());
+/// assert!(Gesture::from_register(0x42).is_none());
+/// assert!(Gesture::from_register(0x4A).is_none());
+/// ```
+#[cfg(feature = "gestures")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Gesture {
+    /// No gesture detected
+    None = 0x00,
+    /// Move up
+    MoveUp = 0x10,
+    /// Move right
+    MoveRight = 0x14,
+    /// Move down
+    MoveDown = 0x18,
+    /// Move left
+    MoveLeft = 0x1C,
+    /// Zoom in
+    ZoomIn = 0x48,
+    /// Zoom out
+    ZoomOut = 0x49,
+}
+
+#[cfg(feature = "gestures")]
+impl Gesture {
+    /// Convert from raw register value
+    ///
+    /// Returns `None` for codes missing from the datasheet table in the
+    /// [`Gesture`] docs.
+    pub fn from_register(val: u8) -> Option<Self> {
+        match val {
+            0x00 => Some(Self::None),
+            0x10 => Some(Self::MoveUp),
+            0x14 => Some(Self::MoveRight),
+            0x18 => Some(Self::MoveDown),
+            0x1C => Some(Self::MoveLeft),
+            0x48 => Some(Self::ZoomIn),
+            0x49 => Some(Self::ZoomOut),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "gestures")]
+impl TryFrom<u8> for Gesture {
+    type Error = InvalidEnumValue;
+
+    /// Same as [`Gesture::from_register`], with the rejected value as the error
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Self::from_register(val).ok_or(InvalidEnumValue(val))
+    }
+}
+
+/// Identification and mode registers read in one burst
+///
+/// Returned by the driver's `device_info()` method, which reads the
+/// contiguous system information registers (`0xA1` through `0xA6`) in a
+/// single transaction.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{DeviceInfo, GestureMode, CHIP_ID};
+///
+/// let info = DeviceInfo {
+///     library_version: 0x0303,
+///     chip_id: CHIP_ID,
+///     gesture_mode: GestureMode::Polling,
+///     power_mode: 0,
+///     firmware_id: 0x10,
+/// };
+/// assert!(info.is_ft6336u());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// Library version (`LIB_VER_H`/`LIB_VER_L`)
+    pub library_version: u16,
+    /// Chip ID (0x64 for FT6336U)
+    pub chip_id: u8,
+    /// Interrupt mode (`G_MODE`)
+    pub gesture_mode: GestureMode,
+    /// Power mode value
+    pub power_mode: u8,
+    /// Firmware ID
+    pub firmware_id: u8,
+}
+
+impl DeviceInfo {
+    /// Whether the chip ID matches [`CHIP_ID`]
+    pub fn is_ft6336u(&self) -> bool {
+        self.chip_id == CHIP_ID
+    }
+}
+
+/// How the driver came to read the latest frame
+///
+/// Returned by the driver's `last_scan_source()` method, so logging can tell
+/// frames read after an interrupt from frames read by plain polling.
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::ScanSource;
+///
+/// assert_eq!(ScanSource::default(), ScanSource::Poll);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ScanSource {
+    /// Read by `wait_for_touch()` after the INT pin went low
+    Interrupt,
+    /// Read by a scan method called directly
+    #[default]
+    Poll,
+}
+
 /// Touch event status for a single touch point
 ///
 /// Indicates whether a touch is new, continuing, or has been released.
@@ -121,7 +430,7 @@ impl GestureMode {
 /// // A new touch starts as Touch, then becomes Stream for continuous contact
 /// let status = TouchStatus::Touch;
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TouchStatus {
     /// Initial touch detected
     Touch,
@@ -131,6 +440,129 @@ pub enum TouchStatus {
     Release,
 }
 
+impl TouchStatus {
+    /// Stable numeric value for serialized frames
+    ///
+    /// The mapping is fixed and independent of the variant order:
+    ///
+    /// | Status    | Value |
+    /// |-----------|-------|
+    /// | `Touch`   | 0     |
+    /// | `Stream`  | 1     |
+    /// | `Release` | 2     |
+    ///
+    /// New variants will only ever take values that aren't listed here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::TouchStatus;
+    ///
+    /// assert_eq!(TouchStatus::Stream.as_u8(), 1);
+    /// assert_eq!(TouchStatus::from_u8(2), Some(TouchStatus::Release));
+    /// assert_eq!(TouchStatus::from_u8(3), None);
+    ///
+    /// for status in [TouchStatus::Touch, TouchStatus::Stream, TouchStatus::Release] {
+    ///     assert_eq!(TouchStatus::from_u8(status.as_u8()), Some(status));
+    /// }
+    /// ```
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Self::Touch => 0,
+            Self::Stream => 1,
+            Self::Release => 2,
+        }
+    }
+
+    /// Convert from the value returned by [`Self::as_u8`]
+    ///
+    /// Returns `None` for values with no status assigned.
+    pub fn from_u8(val: u8) -> Option<Self> {
+        match val {
+            0 => Some(Self::Touch),
+            1 => Some(Self::Stream),
+            2 => Some(Self::Release),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for TouchStatus {
+    type Error = InvalidEnumValue;
+
+    /// Same as [`TouchStatus::from_u8`], with the rejected value as the error
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Self::from_u8(val).ok_or(InvalidEnumValue(val))
+    }
+}
+
+/// Event flag reported by the controller for a touch point
+///
+/// The typed form of [`TouchPoint::raw_event`]. [`PRES_DOWN`] and
+/// [`COORD_UD`] are the reference driver's names for [`Self::Contact`] and
+/// [`Self::LiftUp`].
+///
+/// [`PRES_DOWN`]: crate::PRES_DOWN
+/// [`COORD_UD`]: crate::COORD_UD
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{PointEvent, COORD_UD, PRES_DOWN};
+///
+/// assert_eq!(PointEvent::from_register(PRES_DOWN), PointEvent::Contact);
+/// assert_eq!(PointEvent::from_register(COORD_UD), PointEvent::LiftUp);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PointEvent {
+    /// The finger was just pressed
+    PressDown = EVENT_PRESS_DOWN,
+    /// The finger was just lifted
+    LiftUp = EVENT_LIFT_UP,
+    /// The finger is held down
+    Contact = EVENT_CONTACT,
+    /// No event for this point
+    NoEvent = EVENT_NO_EVENT,
+}
+
+impl PointEvent {
+    /// Convert from an event flag, ignoring everything above its two bits
+    pub fn from_register(val: u8) -> Self {
+        match val & 0b11 {
+            EVENT_PRESS_DOWN => Self::PressDown,
+            EVENT_LIFT_UP => Self::LiftUp,
+            EVENT_CONTACT => Self::Contact,
+            _ => Self::NoEvent,
+        }
+    }
+}
+
+/// Same as [`PointEvent::from_register`]; every value maps to an event
+impl From<u8> for PointEvent {
+    fn from(val: u8) -> Self {
+        Self::from_register(val)
+    }
+}
+
+/// Coordinate type of touch points and frames
+///
+/// `u16` by default. The `i16-coords` feature makes it `i16` for code that
+/// works in signed space, saving casts when computing deltas or transforms.
+/// Scanned coordinates are never negative either way: raw values are 12-bit
+/// (0 to 4095) and fit both types. With `i16`, a display size passed to the
+/// driver's `set_scaling()` must stay below 32768 so scaled coordinates fit.
+#[cfg(not(feature = "i16-coords"))]
+pub type Coord = u16;
+/// Coordinate type of touch points and frames
+///
+/// `i16` because the `i16-coords` feature is enabled; `u16` otherwise.
+/// Scanned coordinates are never negative: raw values are 12-bit (0 to 4095),
+/// and a display size passed to the driver's `set_scaling()` must stay below
+/// 32768 so scaled coordinates fit.
+#[cfg(feature = "i16-coords")]
+pub type Coord = i16;
+
 /// A single touch point with coordinates and status
 ///
 /// Represents one touch point detected by the FT6336U. The controller can detect
@@ -145,18 +577,43 @@ pub enum TouchStatus {
 ///     status: TouchStatus::Touch,
 ///     x: 120,
 ///     y: 240,
+///     ..Default::default()
 /// };
 ///
 /// println!("Touch detected at ({}, {})", point.x, point.y);
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TouchPoint {
     /// Touch status
     pub status: TouchStatus,
     /// X coordinate
-    pub x: u16,
+    pub x: Coord,
     /// Y coordinate
-    pub y: u16,
+    pub y: Coord,
+    /// How long the point has been continuously pressed, in milliseconds
+    ///
+    /// Only tracked by the driver's timestamped `scan_at()`; plain `scan()`
+    /// leaves it at 0. Reset to 0 on each new `Touch` and kept at its final
+    /// value after `Release`.
+    pub pressed_ms: u32,
+    /// Event flag from the hardware, unmodified (bits 7:6 of `Pn_XH`)
+    ///
+    /// One of the `EVENT_*` constants, from the last report that included
+    /// this point. Unlike `status`, which the driver derives by comparing
+    /// frames, this is what the controller itself said, so a mismatch points
+    /// at a firmware quirk or a wrong assumption about the event sequence.
+    /// Points the driver releases because they stopped being reported keep
+    /// their last value.
+    pub raw_event: u8,
+    /// Touch weight from the `Pn_WEIGHT` register, from the last report that
+    /// included this point
+    pub weight: u8,
+    /// Touch ID from the hardware (bits 7:4 of `Pn_YH`), from the last report
+    /// that included this point
+    ///
+    /// Normally the same as the point's slot, but it can differ when IDs
+    /// collide, with positional tracking, or with single-touch emulation.
+    pub id: u8,
 }
 
 impl Default for TouchPoint {
@@ -165,10 +622,118 @@ impl Default for TouchPoint {
             status: TouchStatus::Release,
             x: 0,
             y: 0,
+            pressed_ms: 0,
+            raw_event: EVENT_NO_EVENT,
+            weight: 0,
+            id: 0,
         }
     }
 }
 
+impl TouchPoint {
+    /// Whether this point is currently in contact (`Touch` or `Stream`)
+    pub fn is_active(&self) -> bool {
+        self.status != TouchStatus::Release
+    }
+
+    /// The controller's event flag for this point, from [`Self::raw_event`]
+    pub fn event(&self) -> PointEvent {
+        PointEvent::from_register(self.raw_event)
+    }
+
+    /// Whether this point is more than `threshold` counts away from `other`
+    ///
+    /// Uses the Chebyshev distance, the larger of the X and Y differences. It
+    /// needs no multiplication and gives a square dead zone, which matches the
+    /// sensor's independent X and Y axes. Status is ignored.
+    ///
+    /// # Arguments
+    /// * `other` - The point to compare against, typically the press position
+    /// * `threshold` - Largest movement on either axis still considered stationary
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::TouchPoint;
+    ///
+    /// let down = TouchPoint { x: 100, y: 100, ..Default::default() };
+    /// let jitter = TouchPoint { x: 103, y: 98, ..Default::default() };
+    /// let drag = TouchPoint { x: 100, y: 120, ..Default::default() };
+    ///
+    /// assert!(!jitter.moved_more_than(&down, 5));
+    /// assert!(drag.moved_more_than(&down, 5));
+    /// ```
+    pub fn moved_more_than(&self, other: &TouchPoint, threshold: u16) -> bool {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)) > threshold
+    }
+
+    /// Squared Euclidean distance to `other`
+    ///
+    /// Compare against a squared radius to avoid a square root. Saturates at
+    /// `u32::MAX` rather than overflowing. Status is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::TouchPoint;
+    ///
+    /// let a = TouchPoint { x: 0, y: 0, ..Default::default() };
+    /// let b = TouchPoint { x: 30, y: 40, ..Default::default() };
+    /// assert_eq!(a.squared_distance_to(&b), 2500);
+    /// ```
+    pub fn squared_distance_to(&self, other: &TouchPoint) -> u32 {
+        let squared = squared_distance((self.x, self.y), (other.x, other.y));
+        u32::try_from(squared).unwrap_or(u32::MAX)
+    }
+
+    /// Manhattan distance to `other`, the sum of the X and Y differences
+    ///
+    /// Status is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::TouchPoint;
+    ///
+    /// let a = TouchPoint { x: 10, y: 50, ..Default::default() };
+    /// let b = TouchPoint { x: 40, y: 10, ..Default::default() };
+    /// assert_eq!(a.manhattan_to(&b), 70);
+    /// ```
+    pub fn manhattan_to(&self, other: &TouchPoint) -> u32 {
+        (self.x.abs_diff(other.x) as u32).saturating_add(self.y.abs_diff(other.y) as u32)
+    }
+}
+
+/// Extract the `(x, y)` coordinates of a point
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::{Coord, TouchPoint};
+///
+/// let point = TouchPoint {
+///     x: 120,
+///     y: 240,
+///     ..Default::default()
+/// };
+///
+/// let (x, y) = point.into();
+/// assert_eq!((x, y), (120, 240));
+/// assert_eq!(<(Coord, Coord)>::from(&point), (120, 240));
+/// ```
+impl From<TouchPoint> for (Coord, Coord) {
+    fn from(point: TouchPoint) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// Extract the `(x, y)` coordinates of a borrowed point
+impl From<&TouchPoint> for (Coord, Coord) {
+    fn from(point: &TouchPoint) -> Self {
+        (point.x, point.y)
+    }
+}
+
 /// Complete touch data including up to 2 touch points
 ///
 /// Contains the results of a touch scan, including the number of active touches
@@ -194,5 +759,355 @@ pub struct TouchData {
     /// Number of active touch points (0-2)
     pub touch_count: u8,
     /// Touch point data (up to 2 points)
-    pub points: [TouchPoint; 2],
+    pub points: [TouchPoint; MAX_TOUCH_POINTS],
+    /// Sequence number of the scan that produced this frame
+    ///
+    /// The driver counts every scan, starting from 1 for the first, so a gap
+    /// between consecutive frames means one was missed. The counter wraps
+    /// from `u32::MAX` back to 0, so compare with `wrapping_sub`. Frames built
+    /// by hand have 0, and a frame synthesized without scanning, such as by
+    /// `release_all()`, repeats the number of the last scan.
+    ///
+    /// It is not part of equality or hashing, so two scans of the same touch
+    /// state compare equal.
+    pub seq: u32,
+}
+
+/// Compares the touch state, ignoring `seq`
+///
+/// # Examples
+///
+/// ```rust
+/// use ft6336u_driver::TouchData;
+///
+/// let first = TouchData { seq: 1, ..Default::default() };
+/// let second = TouchData { seq: 2, ..Default::default() };
+/// assert_eq!(first, second);
+/// ```
+impl PartialEq for TouchData {
+    fn eq(&self, other: &Self) -> bool {
+        self.touch_count == other.touch_count && self.points == other.points
+    }
+}
+
+impl Eq for TouchData {}
+
+/// Hashes the touch state, ignoring `seq`, consistently with `PartialEq`
+impl core::hash::Hash for TouchData {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.touch_count.hash(state);
+        self.points.hash(state);
+    }
+}
+
+/// Positions of up to two active touch points, in report order
+///
+/// Returned by the driver's `positions()` method. Active points come first and
+/// unused slots are `None`.
+pub type Positions = [Option<(Coord, Coord)>; MAX_TOUCH_POINTS];
+
+impl TouchData {
+    /// The single touch point that matters most
+    ///
+    /// Points are stored by hardware touch ID, so this returns the active point
+    /// with the lowest ID: slot 0 if it is active, otherwise slot 1. When only
+    /// one finger is down, that finger is returned whichever slot it is in.
+    ///
+    /// # Returns
+    /// The primary active point, or `None` if nothing is touching
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert!(data.primary().is_none());
+    ///
+    /// // Only the finger with ID 1 is down
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].x = 42;
+    /// assert_eq!(data.primary().unwrap().x, 42);
+    /// ```
+    pub fn primary(&self) -> Option<&TouchPoint> {
+        self.points.iter().find(|p| p.is_active())
+    }
+
+    /// Bitmask of the active point slots
+    ///
+    /// Bit 0 is set when slot 0 is active and bit 1 when slot 1 is active, so
+    /// comparing masks is a cheap way to detect fingers landing or lifting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert_eq!(data.touch_mask(), 0b00);
+    ///
+    /// data.points[1].status = TouchStatus::Stream;
+    /// assert_eq!(data.touch_mask(), 0b10);
+    ///
+    /// data.points[0].status = TouchStatus::Touch;
+    /// assert_eq!(data.touch_mask(), 0b11);
+    /// ```
+    pub fn touch_mask(&self) -> u8 {
+        self.points
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_active())
+            .fold(0, |mask, (i, _)| mask | (1 << i))
+    }
+
+    /// Number of active points
+    ///
+    /// Counts the slots that are actually active. This usually equals
+    /// `touch_count`, but the two can disagree: `touch_count` is what the
+    /// controller reported, while points are stored by touch ID and adjusted
+    /// by the driver's filters.
+    pub fn active_count(&self) -> usize {
+        self.points.iter().filter(|p| p.is_active()).count()
+    }
+
+    /// Hardware touch IDs of the active points, in slot order
+    ///
+    /// Active points fill the array from the front and the remaining entries
+    /// are `None`, so the number of `Some` entries equals
+    /// [`Self::active_count`]. IDs come from the controller, so they let
+    /// fingers be correlated across frames even when the driver stores them
+    /// by report order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert_eq!(data.active_ids(), [None, None]);
+    ///
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].id = 1;
+    /// assert_eq!(data.active_ids(), [Some(1), None]);
+    ///
+    /// data.points[0].status = TouchStatus::Stream;
+    /// data.points[0].id = 0;
+    /// assert_eq!(data.active_ids(), [Some(0), Some(1)]);
+    /// assert_eq!(data.active_ids().iter().flatten().count(), data.active_count());
+    /// ```
+    pub fn active_ids(&self) -> [Option<u8>; 2] {
+        let mut active = self.points.iter().filter(|p| p.is_active());
+        [active.next(), active.next()].map(|point| point.map(|p| p.id))
+    }
+
+    /// Whether no point is active
+    ///
+    /// Consistent with [`Self::active_count`], so it reflects the points that
+    /// are actually active rather than `touch_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert!(data.is_empty());
+    ///
+    /// data.points[1].status = TouchStatus::Touch;
+    /// assert!(!data.is_empty());
+    /// assert_eq!(data.active_count(), 1);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.active_count() == 0
+    }
+
+    /// Centroid of the active touch points
+    ///
+    /// # Returns
+    /// The average position of all active points, or `None` if nothing is touching
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert_eq!(data.centroid(), None);
+    ///
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].x = 100;
+    /// data.points[0].y = 100;
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].x = 200;
+    /// data.points[1].y = 300;
+    /// assert_eq!(data.centroid(), Some((150, 200)));
+    /// ```
+    pub fn centroid(&self) -> Option<(Coord, Coord)> {
+        let mut sum_x = 0u32;
+        let mut sum_y = 0u32;
+        let mut count = 0u32;
+        for point in self.points.iter().filter(|p| p.is_active()) {
+            sum_x += point.x as u32;
+            sum_y += point.y as u32;
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        Some(((sum_x / count) as Coord, (sum_y / count) as Coord))
+    }
+
+    /// Distance between the two touch points
+    ///
+    /// # Returns
+    /// The integer Euclidean distance, or `None` unless both points are active
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].x = 0;
+    /// data.points[0].y = 0;
+    /// assert_eq!(data.pinch_distance(), None);
+    ///
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].x = 30;
+    /// data.points[1].y = 40;
+    /// assert_eq!(data.pinch_distance(), Some(50));
+    ///
+    /// // Distances that aren't whole numbers are rounded down
+    /// for ((x, y), distance) in [
+    ///     ((0, 0), 0),
+    ///     ((1, 0), 1),
+    ///     ((1, 1), 1),
+    ///     ((2, 0), 2),
+    ///     ((3, 3), 4),
+    ///     ((4095, 4095), 5791),
+    /// ] {
+    ///     data.points[1].x = x;
+    ///     data.points[1].y = y;
+    ///     assert_eq!(data.pinch_distance(), Some(distance));
+    /// }
+    /// ```
+    pub fn pinch_distance(&self) -> Option<u16> {
+        let [a, b] = &self.points;
+        if !a.is_active() || !b.is_active() {
+            return None;
+        }
+        Some(isqrt(a.squared_distance_to(b)))
+    }
+
+    /// Smallest rectangle containing every active touch point
+    ///
+    /// With a single active point the box has zero area, with both corners at
+    /// that point.
+    ///
+    /// # Returns
+    /// `(min_x, min_y, max_x, max_y)`, or `None` if nothing is touching
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert_eq!(data.bounding_box(), None);
+    ///
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].x = 200;
+    /// data.points[0].y = 100;
+    /// assert_eq!(data.bounding_box(), Some((200, 100, 200, 100)));
+    ///
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].x = 50;
+    /// data.points[1].y = 300;
+    /// assert_eq!(data.bounding_box(), Some((50, 100, 200, 300)));
+    /// ```
+    pub fn bounding_box(&self) -> Option<(Coord, Coord, Coord, Coord)> {
+        let mut active = self.points.iter().filter(|p| p.is_active());
+        let first = active.next()?;
+        let init = (first.x, first.y, first.x, first.y);
+        Some(active.fold(init, |(min_x, min_y, max_x, max_y), point| {
+            (
+                min_x.min(point.x),
+                min_y.min(point.y),
+                max_x.max(point.x),
+                max_y.max(point.y),
+            )
+        }))
+    }
+
+    /// Active point nearest to `(x, y)`
+    ///
+    /// Compares squared distances, so no square root is needed. On a tie the
+    /// lower slot wins.
+    ///
+    /// # Arguments
+    /// * `x` - Target X coordinate
+    /// * `y` - Target Y coordinate
+    ///
+    /// # Returns
+    /// The closest active point, or `None` if no point is active
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{Coord, TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert!(data.closest_to(0, 0).is_none());
+    ///
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].x = 10;
+    /// data.points[0].y = 10;
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].x = 200;
+    /// data.points[1].y = 150;
+    /// assert_eq!(data.closest_to(180, 160).map(|p| p.x), Some(200));
+    ///
+    /// // Far-away targets are still ordered correctly
+    /// assert_eq!(data.closest_to(Coord::MAX, Coord::MAX).map(|p| p.x), Some(200));
+    /// ```
+    pub fn closest_to(&self, x: Coord, y: Coord) -> Option<&TouchPoint> {
+        self.points
+            .iter()
+            .filter(|p| p.is_active())
+            .min_by_key(|p| squared_distance((p.x, p.y), (x, y)))
+    }
+
+    /// Active points ordered by descending weight, heaviest first
+    ///
+    /// Active points fill the array from the front and the remaining entries
+    /// are `None`. Points with equal weight keep their slot order, so the
+    /// result is stable when pressure doesn't distinguish the fingers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ft6336u_driver::{TouchData, TouchStatus};
+    ///
+    /// let mut data = TouchData::default();
+    /// assert_eq!(data.by_weight(), [None, None]);
+    ///
+    /// data.points[0].status = TouchStatus::Touch;
+    /// data.points[0].weight = 20;
+    /// data.points[1].status = TouchStatus::Touch;
+    /// data.points[1].weight = 45;
+    /// let [first, second] = data.by_weight();
+    /// assert_eq!(first.map(|p| p.weight), Some(45));
+    /// assert_eq!(second.map(|p| p.weight), Some(20));
+    /// ```
+    pub fn by_weight(&self) -> [Option<&TouchPoint>; 2] {
+        let mut active = self.points.iter().filter(|p| p.is_active());
+        let mut ordered = [active.next(), active.next()];
+        if let [Some(first), Some(second)] = ordered {
+            if second.weight > first.weight {
+                ordered.swap(0, 1);
+            }
+        }
+        ordered
+    }
 }
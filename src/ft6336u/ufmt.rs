@@ -0,0 +1,176 @@
+//! `ufmt` output support for the FT6336U driver types.
+//!
+//! This module is only available when the `ufmt` feature is enabled. It writes
+//! the touch types through the [`uWrite`] trait that `ufmt` is built on, so
+//! touch events can be logged without pulling in `core::fmt`. [`TouchPoint`],
+//! [`TouchData`] and [`Error`] also implement `uDisplay` and `uDebug`, so
+//! they work directly with `uwrite!`; both print the same text.
+
+use ufmt::{uDebug, uDisplay, Formatter};
+use ufmt_write::uWrite;
+
+use super::error::Error;
+use super::types::*;
+
+/// Write an unsigned integer in decimal without going through `core::fmt`
+fn write_u16<W: uWrite + ?Sized>(w: &mut W, mut val: u16) -> Result<(), W::Error> {
+    let mut buf = [0u8; 5];
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (val % 10) as u8;
+        val /= 10;
+        if val == 0 {
+            break;
+        }
+    }
+    for &b in &buf[i..] {
+        w.write_char(b as char)?;
+    }
+    Ok(())
+}
+
+/// Lets the `write_ufmt` helpers write through a `ufmt` [`Formatter`]
+struct FormatterWriter<'a, 'w, W: uWrite + ?Sized>(&'a mut Formatter<'w, W>);
+
+impl<W: uWrite + ?Sized> uWrite for FormatterWriter<'_, '_, W> {
+    type Error = W::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> Result<(), Self::Error> {
+        self.0.write_char(c)
+    }
+}
+
+/// Implement `uDisplay` and `uDebug` with the type's `write_ufmt` helper
+macro_rules! impl_ufmt {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> uDisplay for $ty {
+                fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                    self.write_ufmt(&mut FormatterWriter(f))
+                }
+            }
+
+            impl<$($generics)*> uDebug for $ty {
+                fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+                    self.write_ufmt(&mut FormatterWriter(f))
+                }
+            }
+        )*
+    };
+}
+
+impl_ufmt!([] TouchPoint, [] TouchData, [E] Error<E>);
+
+impl TouchStatus {
+    /// Write the status name to a `ufmt` writer
+    pub fn write_ufmt<W: uWrite + ?Sized>(&self, w: &mut W) -> Result<(), W::Error> {
+        w.write_str(match self {
+            TouchStatus::Touch => "Touch",
+            TouchStatus::Stream => "Stream",
+            TouchStatus::Release => "Release",
+        })
+    }
+}
+
+impl TouchPoint {
+    /// Write the point to a `ufmt` writer as `Status(x, y)`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "ufmt")]
+    /// # {
+    /// # struct Buf(String);
+    /// # impl ufmt_write::uWrite for Buf {
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+    /// #         self.0.push_str(s);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// use ft6336u_driver::{TouchPoint, TouchStatus};
+    ///
+    /// let point = TouchPoint {
+    ///     status: TouchStatus::Touch,
+    ///     x: 120,
+    ///     y: 240,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut out = Buf(String::new());
+    /// point.write_ufmt(&mut out).unwrap();
+    /// assert_eq!(out.0, "Touch(120, 240)");
+    ///
+    /// // The same text through `uDisplay`
+    /// let mut out = Buf(String::new());
+    /// ufmt::uwrite!(out, "at {}", point).unwrap();
+    /// assert_eq!(out.0, "at Touch(120, 240)");
+    /// # }
+    /// ```
+    // Scanned coordinates are never negative, whichever type `Coord` is
+    #[allow(clippy::unnecessary_cast)]
+    pub fn write_ufmt<W: uWrite + ?Sized>(&self, w: &mut W) -> Result<(), W::Error> {
+        self.status.write_ufmt(w)?;
+        w.write_char('(')?;
+        write_u16(w, self.x as u16)?;
+        w.write_str(", ")?;
+        write_u16(w, self.y as u16)?;
+        w.write_char(')')
+    }
+}
+
+impl TouchData {
+    /// Write the frame to a `ufmt` writer as `count: [point, point]`
+    pub fn write_ufmt<W: uWrite + ?Sized>(&self, w: &mut W) -> Result<(), W::Error> {
+        write_u16(w, self.touch_count as u16)?;
+        w.write_str(": [")?;
+        self.points[0].write_ufmt(w)?;
+        w.write_str(", ")?;
+        self.points[1].write_ufmt(w)?;
+        w.write_char(']')
+    }
+}
+
+impl<E> Error<E> {
+    /// Write the error kind to a `ufmt` writer
+    ///
+    /// The wrapped I2C error is not written, since bus error types are not
+    /// required to support `ufmt`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "ufmt")]
+    /// # {
+    /// # struct Buf(String);
+    /// # impl ufmt_write::uWrite for Buf {
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+    /// #         self.0.push_str(s);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// use ft6336u_driver::Error;
+    ///
+    /// let err: Error<()> = Error::Timeout;
+    /// let mut out = Buf(String::new());
+    /// ufmt::uwrite!(out, "scan failed: {:?}", err).unwrap();
+    /// assert_eq!(out.0, "scan failed: Timeout");
+    /// # }
+    /// ```
+    pub fn write_ufmt<W: uWrite + ?Sized>(&self, w: &mut W) -> Result<(), W::Error> {
+        w.write_str(match self {
+            Error::I2c(_) => "I2c",
+            Error::InvalidData => "InvalidData",
+            Error::Pin => "Pin",
+            Error::Timeout => "Timeout",
+            Error::WrongChipId(_) => "WrongChipId",
+            Error::NotPresent => "NotPresent",
+        })
+    }
+}
@@ -12,11 +12,25 @@
 //! - Configurable power modes and scan rates
 //! - Comprehensive register access
 //! - Optional async support via the `async` feature flag
+//! - A driver-agnostic [`TouchController`] trait as the stable abstraction point
 //!
 //! ## Feature Flags
 //!
 //! - `async` - Enables async/await support using `embedded-hal-async` traits.
 //!   When this feature is enabled, all I2C operations become asynchronous.
+//! - `ufmt` - Implements `ufmt`'s `uDisplay` and `uDebug` for [`TouchPoint`],
+//!   [`TouchData`] and [`Error`], and adds the `write_ufmt` methods behind
+//!   them, avoiding the code-size cost of `core::fmt` when logging touch events.
+//! - `gestures` (default) - Enables the gesture ID and gesture parameter
+//!   register methods, the `Gesture` and `GestureParams` types, and the
+//!   `gesture` detectors. Disable default features to compile them out when
+//!   only touch coordinates are needed; `scan()` works either way. In a
+//!   size-optimized x86_64 build, turning it off saved under 200 bytes for a
+//!   program using only `scan()` and `restore_defaults()`, and the gesture
+//!   readers themselves cost about 1.1KB once called.
+//! - `i16-coords` - Makes [`Coord`], the coordinate type of [`TouchPoint`],
+//!   [`TouchData`] and the touch events, `i16` instead of `u16` for code that
+//!   works in signed space. Scanned coordinates are never negative either way.
 //!
 //! ## Quick Start
 //!
@@ -101,6 +115,41 @@
 //! - A reset pin (typically controlled by GPIO or GPIO expander)
 //! - An interrupt pin (optional, for event-driven operation)
 //!
+//! ### Sharing the I2C Bus
+//!
+//! The driver owns its `I2C` value, but it only needs something implementing
+//! the `I2c` trait, so a shared-bus device such as
+//! [`embedded-hal-bus`](https://docs.rs/embedded-hal-bus)'s `RefCellDevice`,
+//! `CriticalSectionDevice` or `MutexDevice` can be passed in while other
+//! drivers hold their own devices on the same bus. In async code,
+//! `embassy-embedded-hal`'s shared-bus devices fill the same role.
+//!
+//! ```rust
+//! # #[cfg(not(feature = "async"))]
+//! # {
+//! use core::cell::RefCell;
+//! use embedded_hal_bus::i2c::RefCellDevice;
+//! # use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+//! use ft6336u_driver::{FT6336U, I2C_ADDR};
+//!
+//! # let expectations = [Transaction::write_read(
+//! #     I2C_ADDR,
+//! #     vec![0x02],
+//! #     vec![0x01, 0x81, 0x2C, 0x00, 0xC8, 0x00, 0x00],
+//! # )];
+//! # let mut i2c = Mock::new(&expectations);
+//! let bus = RefCell::new(i2c.clone());
+//!
+//! // Each driver on the bus gets its own device handle
+//! let mut touch = FT6336U::new(RefCellDevice::new(&bus));
+//! // let mut other = OtherDriver::new(RefCellDevice::new(&bus));
+//!
+//! let data = touch.scan().unwrap();
+//! assert_eq!(data.touch_count, 1);
+//! # i2c.done();
+//! # }
+//! ```
+//!
 //! ### Interrupt-Driven Operation
 //!
 //! For optimal power efficiency, configure the touch controller's interrupt pin
@@ -173,4 +222,3 @@ mod ft6336u;
 
 // Re-export the public API
 pub use ft6336u::*;
-